@@ -0,0 +1,364 @@
+-- OpenHWY TMS initial schema
+
+CREATE EXTENSION IF NOT EXISTS postgis;
+
+-- ================================================================
+-- TENANTS & USERS
+-- ================================================================
+
+CREATE TABLE companies (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    name TEXT NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE TABLE users (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    company_id UUID NOT NULL REFERENCES companies(id),
+    email TEXT NOT NULL UNIQUE,
+    password_hash TEXT,
+    first_name TEXT,
+    last_name TEXT,
+    role TEXT NOT NULL DEFAULT 'dispatcher',
+    status TEXT NOT NULL DEFAULT 'active',
+    last_login_at TIMESTAMPTZ,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_users_company ON users(company_id);
+
+-- ================================================================
+-- CUSTOMERS
+-- ================================================================
+
+CREATE TABLE customers (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    company_id UUID NOT NULL REFERENCES companies(id),
+    customer_name TEXT NOT NULL,
+    customer_type TEXT NOT NULL DEFAULT 'shipper',
+    email TEXT,
+    phone TEXT,
+    payment_terms INTEGER NOT NULL DEFAULT 30,
+    credit_limit NUMERIC(12, 2),
+    status TEXT NOT NULL DEFAULT 'active',
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_customers_company ON customers(company_id);
+
+CREATE TABLE customer_slas (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    company_id UUID NOT NULL REFERENCES companies(id),
+    customer_id UUID NOT NULL UNIQUE REFERENCES customers(id),
+    on_time_target_pct DOUBLE PRECISION NOT NULL,
+    tender_acceptance_target_pct DOUBLE PRECISION NOT NULL,
+    edi_214_target_pct DOUBLE PRECISION NOT NULL,
+    edi_214_max_delay_minutes INTEGER NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+-- ================================================================
+-- DRIVERS & EQUIPMENT
+-- ================================================================
+
+CREATE TABLE drivers (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    company_id UUID NOT NULL REFERENCES companies(id),
+    first_name TEXT NOT NULL,
+    last_name TEXT NOT NULL,
+    email TEXT,
+    phone TEXT NOT NULL,
+    cdl_number TEXT NOT NULL,
+    cdl_state TEXT,
+    cdl_class TEXT,
+    cdl_expiry DATE NOT NULL,
+    hire_date DATE,
+    pay_type TEXT NOT NULL,
+    pay_rate NUMERIC(12, 4) NOT NULL,
+    employment_status TEXT NOT NULL DEFAULT 'active',
+    current_status TEXT NOT NULL DEFAULT 'off_duty',
+    current_location GEOMETRY(Point, 4326),
+    last_location_update TIMESTAMPTZ,
+    total_miles BIGINT NOT NULL DEFAULT 0,
+    total_loads INTEGER NOT NULL DEFAULT 0,
+    safety_score DOUBLE PRECISION,
+    on_time_percentage DOUBLE PRECISION,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_drivers_company ON drivers(company_id);
+CREATE INDEX idx_drivers_location ON drivers USING GIST(current_location);
+
+CREATE TABLE trucks (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    company_id UUID NOT NULL REFERENCES companies(id),
+    unit_number TEXT NOT NULL,
+    vin TEXT,
+    make TEXT,
+    model TEXT,
+    year INTEGER,
+    status TEXT NOT NULL DEFAULT 'active',
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    UNIQUE (company_id, unit_number)
+);
+
+CREATE TABLE trailers (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    company_id UUID NOT NULL REFERENCES companies(id),
+    trailer_number TEXT NOT NULL,
+    trailer_type TEXT,
+    status TEXT NOT NULL DEFAULT 'active',
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    UNIQUE (company_id, trailer_number)
+);
+
+-- ================================================================
+-- LOADS
+-- ================================================================
+
+CREATE TABLE loads (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    company_id UUID NOT NULL REFERENCES companies(id),
+    load_number TEXT NOT NULL,
+    reference_number TEXT,
+    bol_number TEXT,
+    load_type TEXT NOT NULL,
+    mode TEXT NOT NULL DEFAULT 'truckload',
+    customer_id UUID REFERENCES customers(id),
+    carrier_id UUID,
+    truck_id UUID REFERENCES trucks(id),
+    trailer_id UUID REFERENCES trailers(id),
+    driver_id UUID REFERENCES drivers(id),
+    equipment_type TEXT,
+    total_weight_lbs INTEGER,
+    total_pieces INTEGER,
+    commodity_description TEXT,
+    origin_city TEXT,
+    origin_state TEXT,
+    destination_city TEXT,
+    destination_state TEXT,
+    shipper_name TEXT,
+    consignee_name TEXT,
+    blind_shipment BOOLEAN NOT NULL DEFAULT FALSE,
+    blind_shipper_name TEXT,
+    blind_consignee_name TEXT,
+    status TEXT NOT NULL DEFAULT 'pending',
+    pickup_date DATE NOT NULL,
+    delivery_date DATE NOT NULL,
+    customer_rate NUMERIC(12, 2),
+    carrier_rate NUMERIC(12, 2),
+    total_revenue NUMERIC(12, 2),
+    total_cost NUMERIC(12, 2),
+    profit_margin NUMERIC(12, 2),
+    total_miles INTEGER,
+    delivered_at TIMESTAMPTZ,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    UNIQUE (company_id, load_number)
+);
+
+CREATE INDEX idx_loads_company_status ON loads(company_id, status);
+CREATE INDEX idx_loads_company_pickup ON loads(company_id, pickup_date);
+CREATE INDEX idx_loads_lane ON loads(company_id, origin_state, destination_state);
+CREATE INDEX idx_loads_driver ON loads(driver_id);
+
+CREATE TABLE load_accessorials (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    company_id UUID NOT NULL REFERENCES companies(id),
+    load_id UUID NOT NULL REFERENCES loads(id) ON DELETE CASCADE,
+    charge_type TEXT NOT NULL,
+    description TEXT,
+    amount NUMERIC(12, 2) NOT NULL,
+    billable BOOLEAN NOT NULL DEFAULT TRUE,
+    created_by UUID NOT NULL REFERENCES users(id),
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_load_accessorials_load ON load_accessorials(load_id);
+CREATE INDEX idx_load_accessorials_type ON load_accessorials(company_id, charge_type);
+
+CREATE TABLE location_history (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    company_id UUID NOT NULL REFERENCES companies(id),
+    load_id UUID REFERENCES loads(id) ON DELETE SET NULL,
+    driver_id UUID REFERENCES drivers(id),
+    truck_id UUID REFERENCES trucks(id),
+    latitude DOUBLE PRECISION NOT NULL,
+    longitude DOUBLE PRECISION NOT NULL,
+    speed_mph DOUBLE PRECISION,
+    source TEXT NOT NULL,
+    recorded_at TIMESTAMPTZ NOT NULL
+);
+
+CREATE INDEX idx_location_history_truck ON location_history(truck_id, source, recorded_at DESC);
+CREATE INDEX idx_location_history_driver ON location_history(driver_id, recorded_at DESC);
+CREATE INDEX idx_location_history_load ON location_history(load_id, recorded_at);
+
+CREATE TABLE customer_tenders (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    company_id UUID NOT NULL REFERENCES companies(id),
+    customer_id UUID NOT NULL REFERENCES customers(id),
+    load_id UUID REFERENCES loads(id) ON DELETE SET NULL,
+    external_reference TEXT,
+    received_at TIMESTAMPTZ NOT NULL,
+    responded_at TIMESTAMPTZ,
+    accepted BOOLEAN NOT NULL,
+    decline_reason TEXT,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_customer_tenders_received ON customer_tenders(company_id, received_at);
+
+CREATE TABLE edi_status_messages (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    company_id UUID NOT NULL REFERENCES companies(id),
+    customer_id UUID NOT NULL REFERENCES customers(id),
+    load_id UUID NOT NULL REFERENCES loads(id) ON DELETE CASCADE,
+    status_code TEXT NOT NULL,
+    event_at TIMESTAMPTZ NOT NULL,
+    sent_at TIMESTAMPTZ NOT NULL
+);
+
+CREATE INDEX idx_edi_status_messages_event ON edi_status_messages(company_id, event_at);
+
+CREATE TABLE fraud_alerts (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    company_id UUID NOT NULL REFERENCES companies(id),
+    load_id UUID NOT NULL REFERENCES loads(id) ON DELETE CASCADE,
+    carrier_id UUID,
+    alert_type TEXT NOT NULL,
+    severity TEXT NOT NULL,
+    details JSONB NOT NULL DEFAULT '{}',
+    status TEXT NOT NULL DEFAULT 'open',
+    resolved_by UUID REFERENCES users(id),
+    resolution_note TEXT,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    resolved_at TIMESTAMPTZ
+);
+
+CREATE INDEX idx_fraud_alerts_open ON fraud_alerts(company_id) WHERE status = 'open';
+
+CREATE TABLE claims (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    company_id UUID NOT NULL REFERENCES companies(id),
+    load_id UUID NOT NULL REFERENCES loads(id),
+    customer_id UUID REFERENCES customers(id),
+    claimed_amount NUMERIC(12, 2) NOT NULL,
+    status TEXT NOT NULL DEFAULT 'filed',
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_claims_load ON claims(load_id);
+
+-- ================================================================
+-- BILLING
+-- ================================================================
+
+CREATE TABLE invoices (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    company_id UUID NOT NULL REFERENCES companies(id),
+    invoice_number TEXT NOT NULL,
+    invoice_type TEXT NOT NULL,
+    customer_id UUID REFERENCES customers(id),
+    load_id UUID REFERENCES loads(id),
+    total_amount NUMERIC(12, 2) NOT NULL,
+    amount_paid NUMERIC(12, 2) NOT NULL DEFAULT 0,
+    balance_due NUMERIC(12, 2) NOT NULL,
+    written_off_amount NUMERIC(12, 2) NOT NULL DEFAULT 0,
+    write_off_reason TEXT,
+    invoice_date DATE NOT NULL,
+    due_date DATE NOT NULL,
+    paid_at TIMESTAMPTZ,
+    status TEXT NOT NULL DEFAULT 'open',
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    UNIQUE (company_id, invoice_number)
+);
+
+CREATE INDEX idx_invoices_customer ON invoices(customer_id, status);
+CREATE INDEX idx_invoices_load ON invoices(load_id);
+
+CREATE TABLE fuel_purchases (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    company_id UUID NOT NULL REFERENCES companies(id),
+    truck_id UUID NOT NULL REFERENCES trucks(id),
+    driver_id UUID REFERENCES drivers(id),
+    load_id UUID REFERENCES loads(id) ON DELETE SET NULL,
+    gallons DOUBLE PRECISION NOT NULL,
+    price_per_gallon NUMERIC(8, 4) NOT NULL,
+    total_amount NUMERIC(12, 2) NOT NULL,
+    location TEXT,
+    purchased_at TIMESTAMPTZ NOT NULL,
+    created_by UUID NOT NULL REFERENCES users(id),
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_fuel_purchases_truck ON fuel_purchases(company_id, truck_id);
+
+-- ================================================================
+-- CONTROLS
+-- ================================================================
+
+CREATE TABLE financial_anomalies (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    company_id UUID NOT NULL REFERENCES companies(id),
+    entry_type TEXT NOT NULL,
+    entity_id UUID,
+    observed_value DOUBLE PRECISION NOT NULL,
+    baseline_value DOUBLE PRECISION,
+    reason TEXT NOT NULL,
+    payload JSONB NOT NULL,
+    status TEXT NOT NULL DEFAULT 'pending',
+    flagged_by UUID NOT NULL REFERENCES users(id),
+    reviewed_by UUID REFERENCES users(id),
+    review_note TEXT,
+    reviewed_at TIMESTAMPTZ,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_financial_anomalies_pending ON financial_anomalies(company_id) WHERE status = 'pending';
+
+CREATE TABLE approval_policies (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    company_id UUID NOT NULL REFERENCES companies(id),
+    action_type TEXT NOT NULL,
+    step_order INTEGER NOT NULL,
+    min_amount NUMERIC(12, 2) NOT NULL DEFAULT 0,
+    approver_role TEXT NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    UNIQUE (company_id, action_type, step_order)
+);
+
+CREATE TABLE approval_requests (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    company_id UUID NOT NULL REFERENCES companies(id),
+    action_type TEXT NOT NULL,
+    entity_id UUID NOT NULL,
+    amount NUMERIC(12, 2) NOT NULL,
+    payload JSONB NOT NULL,
+    status TEXT NOT NULL DEFAULT 'pending',
+    current_step INTEGER NOT NULL,
+    requested_by UUID NOT NULL REFERENCES users(id),
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    resolved_at TIMESTAMPTZ
+);
+
+CREATE INDEX idx_approval_requests_pending ON approval_requests(company_id, action_type, current_step) WHERE status = 'pending';
+
+CREATE TABLE approval_decisions (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    request_id UUID NOT NULL REFERENCES approval_requests(id) ON DELETE CASCADE,
+    step_order INTEGER NOT NULL,
+    approver_id UUID NOT NULL REFERENCES users(id),
+    decision TEXT NOT NULL,
+    comment TEXT,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_approval_decisions_request ON approval_decisions(request_id);
@@ -0,0 +1,38 @@
+-- Outside carriers and the identity screening run each time one is booked.
+
+CREATE TABLE carriers (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    company_id UUID NOT NULL REFERENCES companies(id),
+    legal_name TEXT NOT NULL,
+    dba_name TEXT,
+    mc_number TEXT,
+    dot_number TEXT NOT NULL,
+    dispatcher_name TEXT,
+    dispatcher_email TEXT NOT NULL,
+    dispatcher_phone TEXT NOT NULL,
+    status TEXT NOT NULL DEFAULT 'active',
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    UNIQUE (company_id, dot_number)
+);
+
+ALTER TABLE loads
+    ADD CONSTRAINT loads_carrier_id_fkey FOREIGN KEY (carrier_id) REFERENCES carriers(id);
+
+ALTER TABLE fraud_alerts
+    ADD CONSTRAINT fraud_alerts_carrier_id_fkey FOREIGN KEY (carrier_id) REFERENCES carriers(id);
+
+CREATE TABLE carrier_screenings (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    company_id UUID NOT NULL REFERENCES companies(id),
+    carrier_id UUID NOT NULL REFERENCES carriers(id) ON DELETE CASCADE,
+    load_id UUID REFERENCES loads(id) ON DELETE SET NULL,
+    provider TEXT NOT NULL,
+    score INTEGER NOT NULL CHECK (score BETWEEN 0 AND 100),
+    risk_level TEXT NOT NULL,
+    signals JSONB NOT NULL DEFAULT '[]',
+    screened_by UUID REFERENCES users(id),
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_carrier_screenings_carrier ON carrier_screenings(carrier_id, created_at DESC);
@@ -0,0 +1,76 @@
+-- Weekly driver settlements, roadside inspections, and the incentive
+-- programs that post bonuses onto settlements.
+
+CREATE TABLE settlements (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    company_id UUID NOT NULL REFERENCES companies(id),
+    driver_id UUID NOT NULL REFERENCES drivers(id),
+    period_start DATE NOT NULL,
+    period_end DATE NOT NULL,
+    status TEXT NOT NULL DEFAULT 'open',
+    total_amount NUMERIC(12, 2) NOT NULL DEFAULT 0,
+    approved_at TIMESTAMPTZ,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    UNIQUE (driver_id, period_start)
+);
+
+CREATE INDEX idx_settlements_company_period ON settlements(company_id, period_start);
+
+CREATE TABLE settlement_lines (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    settlement_id UUID NOT NULL REFERENCES settlements(id) ON DELETE CASCADE,
+    line_type TEXT NOT NULL,
+    description TEXT NOT NULL,
+    load_id UUID REFERENCES loads(id) ON DELETE SET NULL,
+    amount NUMERIC(12, 2) NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_settlement_lines_settlement ON settlement_lines(settlement_id);
+
+CREATE TABLE inspections (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    company_id UUID NOT NULL REFERENCES companies(id),
+    driver_id UUID NOT NULL REFERENCES drivers(id),
+    truck_id UUID REFERENCES trucks(id),
+    report_number TEXT,
+    inspection_level INTEGER,
+    violation_count INTEGER NOT NULL DEFAULT 0,
+    out_of_service BOOLEAN NOT NULL DEFAULT FALSE,
+    inspected_at TIMESTAMPTZ NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_inspections_driver ON inspections(driver_id, inspected_at);
+
+CREATE TABLE incentive_programs (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    company_id UUID NOT NULL REFERENCES companies(id),
+    program_type TEXT NOT NULL,
+    name TEXT NOT NULL,
+    bonus_amount NUMERIC(12, 2) NOT NULL,
+    threshold NUMERIC(12, 2),
+    active BOOLEAN NOT NULL DEFAULT TRUE,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_incentive_programs_active ON incentive_programs(company_id) WHERE active;
+
+CREATE TABLE incentive_awards (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    company_id UUID NOT NULL REFERENCES companies(id),
+    program_id UUID NOT NULL REFERENCES incentive_programs(id),
+    driver_id UUID NOT NULL REFERENCES drivers(id),
+    period_start DATE NOT NULL,
+    period_end DATE NOT NULL,
+    amount NUMERIC(12, 2) NOT NULL,
+    metric_value DOUBLE PRECISION NOT NULL,
+    details JSONB NOT NULL DEFAULT '{}',
+    settlement_line_id UUID REFERENCES settlement_lines(id) ON DELETE SET NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    UNIQUE (program_id, driver_id, period_start)
+);
+
+CREATE INDEX idx_incentive_awards_driver ON incentive_awards(driver_id, period_start);
@@ -0,0 +1,74 @@
+-- Paid time off for company drivers: accrual policies, balances with a
+-- ledger, time-off requests, and the driver calendar they land on.
+
+ALTER TABLE drivers ADD COLUMN terminated_on DATE;
+
+CREATE TABLE pto_policies (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    company_id UUID NOT NULL REFERENCES companies(id),
+    name TEXT NOT NULL,
+    accrual_rule TEXT NOT NULL,
+    accrual_hours NUMERIC(8, 2) NOT NULL,
+    waiting_period_days INTEGER NOT NULL DEFAULT 0,
+    max_balance_hours NUMERIC(8, 2),
+    hourly_value NUMERIC(12, 2) NOT NULL,
+    payout_on_termination BOOLEAN NOT NULL DEFAULT TRUE,
+    active BOOLEAN NOT NULL DEFAULT TRUE,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE TABLE pto_accounts (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    company_id UUID NOT NULL REFERENCES companies(id),
+    driver_id UUID NOT NULL UNIQUE REFERENCES drivers(id),
+    policy_id UUID NOT NULL REFERENCES pto_policies(id),
+    balance_hours NUMERIC(8, 2) NOT NULL DEFAULT 0,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE TABLE pto_requests (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    company_id UUID NOT NULL REFERENCES companies(id),
+    driver_id UUID NOT NULL REFERENCES drivers(id),
+    start_date DATE NOT NULL,
+    end_date DATE NOT NULL,
+    hours NUMERIC(8, 2) NOT NULL,
+    reason TEXT,
+    status TEXT NOT NULL DEFAULT 'pending',
+    requested_by UUID NOT NULL REFERENCES users(id),
+    decided_at TIMESTAMPTZ,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    CHECK (end_date >= start_date)
+);
+
+CREATE INDEX idx_pto_requests_driver ON pto_requests(driver_id, start_date);
+
+-- Every balance change. Accruals carry the settlement week they cover so a
+-- week is never accrued twice.
+CREATE TABLE pto_ledger (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    account_id UUID NOT NULL REFERENCES pto_accounts(id) ON DELETE CASCADE,
+    entry_type TEXT NOT NULL,
+    hours NUMERIC(8, 2) NOT NULL,
+    period_start DATE,
+    pto_request_id UUID REFERENCES pto_requests(id) ON DELETE SET NULL,
+    note TEXT,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    UNIQUE (account_id, entry_type, period_start)
+);
+
+CREATE TABLE driver_calendar_events (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    company_id UUID NOT NULL REFERENCES companies(id),
+    driver_id UUID NOT NULL REFERENCES drivers(id),
+    event_type TEXT NOT NULL,
+    title TEXT NOT NULL,
+    starts_on DATE NOT NULL,
+    ends_on DATE NOT NULL,
+    source_id UUID,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_driver_calendar_range ON driver_calendar_events(driver_id, starts_on, ends_on);
@@ -0,0 +1,32 @@
+-- Individual pickup and delivery stops on a load, with appointment windows
+-- and the position each stop holds on the driver's optimized daily route.
+
+CREATE TABLE load_stops (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    company_id UUID NOT NULL REFERENCES companies(id),
+    load_id UUID NOT NULL REFERENCES loads(id) ON DELETE CASCADE,
+    stop_sequence INTEGER NOT NULL,
+    stop_type TEXT NOT NULL,
+    location_name TEXT,
+    address TEXT,
+    city TEXT,
+    state TEXT,
+    postal_code TEXT,
+    latitude DOUBLE PRECISION,
+    longitude DOUBLE PRECISION,
+    window_start TIMESTAMPTZ,
+    window_end TIMESTAMPTZ,
+    service_minutes INTEGER NOT NULL DEFAULT 15,
+    status TEXT NOT NULL DEFAULT 'pending',
+    route_date DATE,
+    route_sequence INTEGER,
+    planned_arrival TIMESTAMPTZ,
+    arrived_at TIMESTAMPTZ,
+    departed_at TIMESTAMPTZ,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    CHECK (window_end IS NULL OR window_start IS NULL OR window_end >= window_start)
+);
+
+CREATE INDEX idx_load_stops_load ON load_stops(load_id, stop_sequence);
+CREATE INDEX idx_load_stops_window ON load_stops(company_id, window_start);
@@ -0,0 +1,29 @@
+-- Latest ETA per load toward its next stop, and daily spend per ETA
+-- provider so paid traffic lookups stay within budget.
+
+CREATE TABLE load_etas (
+    load_id UUID PRIMARY KEY REFERENCES loads(id) ON DELETE CASCADE,
+    company_id UUID NOT NULL REFERENCES companies(id),
+    stop_id UUID NOT NULL REFERENCES load_stops(id) ON DELETE CASCADE,
+    provider TEXT NOT NULL,
+    eta TIMESTAMPTZ NOT NULL,
+    deadline TIMESTAMPTZ,
+    slack_minutes INTEGER,
+    miles_remaining DOUBLE PRECISION NOT NULL,
+    traffic_delay_minutes DOUBLE PRECISION NOT NULL DEFAULT 0,
+    incident BOOLEAN NOT NULL DEFAULT FALSE,
+    at_risk BOOLEAN NOT NULL DEFAULT FALSE,
+    computed_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    next_refresh_at TIMESTAMPTZ NOT NULL
+);
+
+CREATE INDEX idx_load_etas_refresh ON load_etas(next_refresh_at);
+CREATE INDEX idx_load_etas_at_risk ON load_etas(company_id) WHERE at_risk;
+
+CREATE TABLE eta_provider_usage (
+    provider TEXT NOT NULL,
+    usage_date DATE NOT NULL,
+    calls INTEGER NOT NULL DEFAULT 0,
+    spend NUMERIC(12,4) NOT NULL DEFAULT 0,
+    PRIMARY KEY (provider, usage_date)
+);
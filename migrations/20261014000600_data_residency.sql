@@ -0,0 +1,7 @@
+-- Region each tenant's data is pinned to. The home database's companies
+-- table is the directory consulted on every request; regional databases
+-- carry the same schema and a companies row for each tenant they serve.
+
+ALTER TABLE companies
+    ADD COLUMN data_region TEXT NOT NULL DEFAULT 'us'
+    CHECK (data_region IN ('us', 'ca', 'eu'));
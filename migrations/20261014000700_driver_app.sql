@@ -0,0 +1,43 @@
+-- Driver app: the user account each driver signs in with, drivers' answers
+-- to dispatches, stop completion, and documents uploaded from the road.
+
+ALTER TABLE drivers ADD COLUMN user_id UUID REFERENCES users(id);
+
+CREATE UNIQUE INDEX idx_drivers_user ON drivers(user_id) WHERE user_id IS NOT NULL;
+
+ALTER TABLE load_stops ADD COLUMN completed_at TIMESTAMPTZ;
+
+CREATE TABLE dispatch_responses (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    company_id UUID NOT NULL REFERENCES companies(id),
+    load_id UUID NOT NULL REFERENCES loads(id) ON DELETE CASCADE,
+    driver_id UUID NOT NULL REFERENCES drivers(id),
+    response TEXT NOT NULL CHECK (response IN ('accepted', 'rejected')),
+    reason TEXT,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_dispatch_responses_load ON dispatch_responses(load_id, created_at);
+
+CREATE TABLE documents (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    company_id UUID NOT NULL REFERENCES companies(id),
+    load_id UUID REFERENCES loads(id) ON DELETE CASCADE,
+    stop_id UUID REFERENCES load_stops(id) ON DELETE SET NULL,
+    driver_id UUID REFERENCES drivers(id),
+    document_type TEXT NOT NULL,
+    file_name TEXT NOT NULL,
+    content_type TEXT NOT NULL,
+    size_bytes INTEGER NOT NULL,
+    uploaded_by UUID NOT NULL REFERENCES users(id),
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_documents_load ON documents(load_id, created_at);
+CREATE INDEX idx_documents_driver ON documents(driver_id);
+
+-- File bodies live apart from the metadata so listings stay cheap.
+CREATE TABLE document_contents (
+    document_id UUID PRIMARY KEY REFERENCES documents(id) ON DELETE CASCADE,
+    content BYTEA NOT NULL
+);
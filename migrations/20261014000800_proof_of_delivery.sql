@@ -0,0 +1,39 @@
+-- Proof of delivery: what each customer requires before a load counts as
+-- delivered, the receiver details captured at each delivery stop, and the
+-- documents attached to invoices as the POD bundle.
+
+CREATE TABLE customer_pod_requirements (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    company_id UUID NOT NULL REFERENCES companies(id),
+    customer_id UUID NOT NULL UNIQUE REFERENCES customers(id),
+    require_receiver_name BOOLEAN NOT NULL DEFAULT TRUE,
+    require_signature BOOLEAN NOT NULL DEFAULT TRUE,
+    min_photos INTEGER NOT NULL DEFAULT 0 CHECK (min_photos >= 0),
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+-- One per delivery stop, or one per load for loads without stops.
+CREATE TABLE proofs_of_delivery (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    company_id UUID NOT NULL REFERENCES companies(id),
+    load_id UUID NOT NULL REFERENCES loads(id) ON DELETE CASCADE,
+    stop_id UUID REFERENCES load_stops(id) ON DELETE CASCADE,
+    receiver_name TEXT,
+    signature_document_id UUID REFERENCES documents(id),
+    signed_at TIMESTAMPTZ NOT NULL,
+    exceptions TEXT,
+    captured_by UUID NOT NULL REFERENCES users(id),
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE UNIQUE INDEX idx_pod_stop ON proofs_of_delivery(stop_id) WHERE stop_id IS NOT NULL;
+CREATE UNIQUE INDEX idx_pod_load ON proofs_of_delivery(load_id) WHERE stop_id IS NULL;
+
+CREATE TABLE invoice_documents (
+    invoice_id UUID NOT NULL REFERENCES invoices(id) ON DELETE CASCADE,
+    document_id UUID NOT NULL REFERENCES documents(id) ON DELETE CASCADE,
+    attached_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (invoice_id, document_id)
+);
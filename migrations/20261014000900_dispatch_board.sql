@@ -0,0 +1,42 @@
+-- Denormalized read model behind the dispatch board: one row per open
+-- load, kept current by the projector as loads, stops, ETAs and drivers
+-- change.
+
+CREATE TABLE dispatch_board_entries (
+    load_id UUID PRIMARY KEY REFERENCES loads(id) ON DELETE CASCADE,
+    company_id UUID NOT NULL REFERENCES companies(id),
+    load_number TEXT NOT NULL,
+    status TEXT NOT NULL,
+    customer_id UUID,
+    customer_name TEXT,
+    equipment_type TEXT,
+    origin_city TEXT,
+    origin_state TEXT,
+    destination_city TEXT,
+    destination_state TEXT,
+    pickup_date DATE NOT NULL,
+    delivery_date DATE NOT NULL,
+    driver_id UUID,
+    driver_name TEXT,
+    driver_status TEXT,
+    driver_location_at TIMESTAMPTZ,
+    truck_id UUID,
+    truck_unit_number TEXT,
+    carrier_id UUID,
+    carrier_name TEXT,
+    next_stop_id UUID,
+    next_stop_type TEXT,
+    next_stop_city TEXT,
+    next_stop_state TEXT,
+    next_stop_window_start TIMESTAMPTZ,
+    next_stop_window_end TIMESTAMPTZ,
+    stops_total INTEGER NOT NULL DEFAULT 0,
+    stops_completed INTEGER NOT NULL DEFAULT 0,
+    eta TIMESTAMPTZ,
+    eta_at_risk BOOLEAN NOT NULL DEFAULT FALSE,
+    eta_slack_minutes INTEGER,
+    projected_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_dispatch_board_company ON dispatch_board_entries(company_id, pickup_date);
+CREATE INDEX idx_dispatch_board_driver ON dispatch_board_entries(driver_id);
@@ -0,0 +1,32 @@
+-- Electronic signing of rate confirmations. The signer opens a tokenized
+-- link; only a hash of the token is kept. The rate confirmation is
+-- snapshotted when the link is sent so the signer signs exactly what was
+-- sent, and the signed artifact is stored as a document.
+
+ALTER TABLE companies
+    ADD COLUMN require_signed_rate_confirmation BOOLEAN NOT NULL DEFAULT FALSE;
+
+CREATE TABLE signature_requests (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    company_id UUID NOT NULL REFERENCES companies(id),
+    load_id UUID NOT NULL REFERENCES loads(id) ON DELETE CASCADE,
+    carrier_id UUID REFERENCES carriers(id),
+    signer_name TEXT NOT NULL,
+    signer_email TEXT,
+    token_hash TEXT NOT NULL UNIQUE,
+    document JSONB NOT NULL,
+    document_hash TEXT NOT NULL,
+    status TEXT NOT NULL DEFAULT 'pending' CHECK (status IN ('pending', 'signed', 'voided')),
+    expires_at TIMESTAMPTZ NOT NULL,
+    viewed_at TIMESTAMPTZ,
+    signed_at TIMESTAMPTZ,
+    signed_name TEXT,
+    signer_ip TEXT,
+    signer_user_agent TEXT,
+    signed_document_id UUID REFERENCES documents(id),
+    signed_artifact_hash TEXT,
+    created_by UUID NOT NULL REFERENCES users(id),
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_signature_requests_load ON signature_requests(load_id, created_at);
@@ -0,0 +1,25 @@
+-- Company profile: the identifiers, remit-to address and paperwork a
+-- company needs on file before it can bill customers or trade EDI.
+
+CREATE TABLE company_profiles (
+    company_id UUID PRIMARY KEY REFERENCES companies(id),
+    legal_name TEXT,
+    dba_name TEXT,
+    scac TEXT CHECK (scac ~ '^[A-Z]{2,4}$'),
+    mc_number TEXT CHECK (mc_number ~ '^[0-9]{1,8}$'),
+    dot_number TEXT CHECK (dot_number ~ '^[0-9]{1,8}$'),
+    -- Legal name FMCSA has on file for the USDOT number, as of the last check.
+    fmcsa_legal_name TEXT,
+    fmcsa_verified_at TIMESTAMPTZ,
+    remit_to_name TEXT,
+    remit_to_line1 TEXT,
+    remit_to_line2 TEXT,
+    remit_to_city TEXT,
+    remit_to_state TEXT,
+    remit_to_postal_code TEXT,
+    w9_document_id UUID REFERENCES documents(id),
+    insurance_document_id UUID REFERENCES documents(id),
+    insurance_expires_on DATE,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
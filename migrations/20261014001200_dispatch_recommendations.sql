@@ -0,0 +1,18 @@
+-- Inputs for ranking drivers against a load: the equipment each driver
+-- runs, where they call home, and their latest hours-of-service clock.
+
+ALTER TABLE drivers
+    ADD COLUMN equipment_types TEXT[] NOT NULL DEFAULT '{}',
+    ADD COLUMN home_location GEOMETRY(Point, 4326);
+
+-- Latest clock per driver, as reported by the ELD or entered by dispatch.
+CREATE TABLE driver_hos_clocks (
+    driver_id UUID PRIMARY KEY REFERENCES drivers(id) ON DELETE CASCADE,
+    company_id UUID NOT NULL REFERENCES companies(id),
+    drive_minutes_remaining INTEGER NOT NULL CHECK (drive_minutes_remaining >= 0),
+    shift_minutes_remaining INTEGER NOT NULL CHECK (shift_minutes_remaining >= 0),
+    cycle_minutes_remaining INTEGER NOT NULL CHECK (cycle_minutes_remaining >= 0),
+    source TEXT NOT NULL DEFAULT 'manual',
+    recorded_at TIMESTAMPTZ NOT NULL,
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
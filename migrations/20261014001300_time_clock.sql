@@ -0,0 +1,75 @@
+-- Time clock for hourly local drivers: the terminals they punch in at,
+-- their shifts and meal breaks, and the weeks already paid through
+-- settlements.
+
+CREATE TABLE terminals (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    company_id UUID NOT NULL REFERENCES companies(id),
+    name TEXT NOT NULL,
+    -- Two-letter state code; picks the overtime rule.
+    state TEXT NOT NULL,
+    -- IANA zone name. Shifts belong to the local date they started on.
+    timezone TEXT NOT NULL,
+    latitude DOUBLE PRECISION NOT NULL,
+    longitude DOUBLE PRECISION NOT NULL,
+    geofence_radius_meters INTEGER NOT NULL DEFAULT 250 CHECK (geofence_radius_meters > 0),
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    UNIQUE (company_id, name)
+);
+
+ALTER TABLE drivers ADD COLUMN terminal_id UUID REFERENCES terminals(id);
+
+CREATE TABLE time_clock_shifts (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    company_id UUID NOT NULL REFERENCES companies(id),
+    driver_id UUID NOT NULL REFERENCES drivers(id),
+    terminal_id UUID NOT NULL REFERENCES terminals(id),
+    work_date DATE NOT NULL,
+    clock_in_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    clock_in_latitude DOUBLE PRECISION NOT NULL,
+    clock_in_longitude DOUBLE PRECISION NOT NULL,
+    clock_out_at TIMESTAMPTZ,
+    clock_out_latitude DOUBLE PRECISION,
+    clock_out_longitude DOUBLE PRECISION,
+    -- Time between the punches less meal breaks, set at clock-out.
+    worked_minutes INTEGER,
+    -- Set when the office closed a shift the driver forgot to punch out of.
+    closed_by UUID REFERENCES users(id),
+    note TEXT,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    CHECK (clock_out_at IS NULL OR clock_out_at > clock_in_at)
+);
+
+CREATE UNIQUE INDEX idx_time_clock_open_shift ON time_clock_shifts(driver_id) WHERE clock_out_at IS NULL;
+CREATE INDEX idx_time_clock_shifts_driver ON time_clock_shifts(driver_id, work_date);
+
+CREATE TABLE time_clock_meal_breaks (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    shift_id UUID NOT NULL REFERENCES time_clock_shifts(id) ON DELETE CASCADE,
+    started_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    ended_at TIMESTAMPTZ,
+    CHECK (ended_at IS NULL OR ended_at >= started_at)
+);
+
+CREATE UNIQUE INDEX idx_time_clock_open_break ON time_clock_meal_breaks(shift_id) WHERE ended_at IS NULL;
+
+-- Hourly lines carry their hours through to the payroll export.
+ALTER TABLE settlement_lines ADD COLUMN hours NUMERIC(8, 2);
+
+-- One row per driver and settlement week once its hours are on the
+-- settlement; the week's shifts are frozen from then on.
+CREATE TABLE time_clock_payroll_weeks (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    company_id UUID NOT NULL REFERENCES companies(id),
+    driver_id UUID NOT NULL REFERENCES drivers(id),
+    week_start DATE NOT NULL,
+    state TEXT NOT NULL,
+    regular_minutes INTEGER NOT NULL,
+    overtime_minutes INTEGER NOT NULL,
+    double_time_minutes INTEGER NOT NULL,
+    hourly_rate NUMERIC(12, 4) NOT NULL,
+    amount NUMERIC(12, 2) NOT NULL,
+    posted_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    UNIQUE (driver_id, week_start)
+);
@@ -0,0 +1,40 @@
+-- Accounts payable for outside carriers: the invoices they send, matched
+-- against the rate confirmation and the accessorials agreed to pay them.
+
+-- Detention, lumper and similar charges owed to the carrier. These count
+-- toward the load's cost and toward what a carrier invoice may claim.
+ALTER TABLE load_accessorials ADD COLUMN carrier_payable BOOLEAN NOT NULL DEFAULT false;
+
+-- How far an invoice may stray from the expected total and still be paid
+-- without review: the larger of the flat amount and the percentage.
+ALTER TABLE companies ADD COLUMN carrier_invoice_tolerance_amount NUMERIC(12, 2) NOT NULL DEFAULT 0;
+ALTER TABLE companies ADD COLUMN carrier_invoice_tolerance_percent NUMERIC(5, 2) NOT NULL DEFAULT 0;
+
+CREATE TABLE carrier_invoices (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    company_id UUID NOT NULL REFERENCES companies(id),
+    carrier_id UUID NOT NULL REFERENCES carriers(id),
+    load_id UUID NOT NULL REFERENCES loads(id),
+    invoice_number TEXT NOT NULL,
+    invoice_date DATE NOT NULL,
+    -- [{charge_type, description, amount}] as submitted.
+    lines JSONB NOT NULL,
+    total_amount NUMERIC(12, 2) NOT NULL,
+    expected_amount NUMERIC(12, 2) NOT NULL,
+    variance NUMERIC(12, 2) NOT NULL,
+    -- Expected and invoiced amounts per charge type at submission.
+    comparison JSONB NOT NULL,
+    exception_reasons TEXT[] NOT NULL DEFAULT '{}',
+    status TEXT NOT NULL CHECK (status IN ('approved', 'exception', 'rejected')),
+    auto_approved BOOLEAN NOT NULL DEFAULT false,
+    submitted_by UUID NOT NULL REFERENCES users(id),
+    resolved_by UUID REFERENCES users(id),
+    resolved_at TIMESTAMPTZ,
+    resolution_note TEXT,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    UNIQUE (carrier_id, invoice_number)
+);
+
+CREATE INDEX idx_carrier_invoices_load ON carrier_invoices(load_id);
+CREATE INDEX idx_carrier_invoices_exceptions ON carrier_invoices(company_id, created_at) WHERE status = 'exception';
@@ -0,0 +1,50 @@
+-- Trips: a driver's sequential loads planned and dispatched together,
+-- e.g. a headhaul and the backhaul that brings the truck home.
+
+-- What a mile costs the fleet to run, for trip profitability. Unset means
+-- profitability covers only what the loads themselves cost.
+ALTER TABLE companies ADD COLUMN operating_cost_per_mile NUMERIC(8, 4);
+
+CREATE TABLE trips (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    company_id UUID NOT NULL REFERENCES companies(id),
+    driver_id UUID NOT NULL REFERENCES drivers(id),
+    truck_id UUID REFERENCES trucks(id),
+    trailer_id UUID REFERENCES trailers(id),
+    status TEXT NOT NULL DEFAULT 'planned'
+        CHECK (status IN ('planned', 'dispatched', 'completed', 'cancelled')),
+    -- Where the trip begins and ends, e.g. the driver's home terminal.
+    -- Without a start the driver's last reported position is used.
+    start_latitude DOUBLE PRECISION,
+    start_longitude DOUBLE PRECISION,
+    end_latitude DOUBLE PRECISION,
+    end_longitude DOUBLE PRECISION,
+    planned_start TIMESTAMPTZ,
+    notes TEXT,
+    optimized_at TIMESTAMPTZ,
+    created_by UUID NOT NULL REFERENCES users(id),
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_trips_company ON trips(company_id, status, created_at);
+CREATE INDEX idx_trips_driver ON trips(driver_id, status);
+
+-- A load rides on at most one trip; cancelling a trip releases its loads.
+CREATE TABLE trip_loads (
+    trip_id UUID NOT NULL REFERENCES trips(id) ON DELETE CASCADE,
+    load_id UUID NOT NULL REFERENCES loads(id) ON DELETE CASCADE,
+    added_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (trip_id, load_id)
+);
+
+CREATE UNIQUE INDEX idx_trip_loads_load ON trip_loads(load_id);
+
+-- The optimized stop order. Cleared whenever the trip's loads change.
+CREATE TABLE trip_stops (
+    trip_id UUID NOT NULL REFERENCES trips(id) ON DELETE CASCADE,
+    stop_id UUID NOT NULL REFERENCES load_stops(id) ON DELETE CASCADE,
+    sequence INTEGER NOT NULL,
+    planned_arrival TIMESTAMPTZ,
+    PRIMARY KEY (trip_id, stop_id)
+);
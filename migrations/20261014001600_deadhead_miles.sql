@@ -0,0 +1,9 @@
+-- Deadhead: where the driver was when a load was dispatched and the empty
+-- miles from there to its first pickup, kept apart from the loaded miles
+-- in total_miles.
+
+ALTER TABLE loads ADD COLUMN dispatch_latitude DOUBLE PRECISION;
+ALTER TABLE loads ADD COLUMN dispatch_longitude DOUBLE PRECISION;
+ALTER TABLE loads ADD COLUMN deadhead_miles DOUBLE PRECISION CHECK (deadhead_miles >= 0);
+
+CREATE INDEX idx_loads_deadhead ON loads(company_id, pickup_date) WHERE deadhead_miles IS NOT NULL;
@@ -0,0 +1,22 @@
+-- Dispatch offers: a dispatched load waits on its driver to accept it
+-- from the driver app, and goes back to dispatch if the driver declines
+-- or lets the offer run out.
+
+-- How long a driver has to answer an offer.
+ALTER TABLE companies ADD COLUMN dispatch_offer_window_minutes INTEGER NOT NULL DEFAULT 120
+    CHECK (dispatch_offer_window_minutes > 0);
+
+ALTER TABLE loads ADD COLUMN offered_at TIMESTAMPTZ;
+ALTER TABLE loads ADD COLUMN offer_expires_at TIMESTAMPTZ;
+
+CREATE INDEX idx_loads_open_offers ON loads(offer_expires_at)
+    WHERE status = 'dispatched' AND offer_expires_at IS NOT NULL;
+
+-- Offers nobody answered are recorded alongside accepts and rejects, with
+-- when each offer was made so response times can be measured.
+ALTER TABLE dispatch_responses ADD COLUMN offered_at TIMESTAMPTZ;
+ALTER TABLE dispatch_responses DROP CONSTRAINT dispatch_responses_response_check;
+ALTER TABLE dispatch_responses ADD CONSTRAINT dispatch_responses_response_check
+    CHECK (response IN ('accepted', 'rejected', 'expired'));
+
+CREATE INDEX idx_dispatch_responses_company ON dispatch_responses(company_id, created_at);
@@ -0,0 +1,19 @@
+-- Daily expected-empty reports: drivers about to be empty paired with
+-- uncovered loads near where they'll be, kept per company and day for
+-- planners and the matching engine.
+
+CREATE TABLE expected_empty_reports (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    company_id UUID NOT NULL REFERENCES companies(id),
+    report_date DATE NOT NULL,
+    horizon_hours INTEGER NOT NULL,
+    uncovered_loads INTEGER NOT NULL,
+    -- [{driver, empty_at, location, hours remaining, matches}] as generated.
+    drivers JSONB NOT NULL,
+    emailed_to TEXT[] NOT NULL DEFAULT '{}',
+    generated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    UNIQUE (company_id, report_date)
+);
+
+CREATE INDEX idx_loads_uncovered ON loads(company_id, pickup_date)
+    WHERE status = 'pending' AND driver_id IS NULL AND carrier_id IS NULL;
@@ -0,0 +1,73 @@
+-- Trailer pool: the customer facilities trailers get dropped at, where
+-- each trailer is now, the driver-recorded drop and hook events that put
+-- it there, and the daily alerts for trailers sitting too long.
+
+CREATE TABLE customer_facilities (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    company_id UUID NOT NULL REFERENCES companies(id),
+    customer_id UUID NOT NULL REFERENCES customers(id),
+    name TEXT NOT NULL,
+    address TEXT,
+    city TEXT,
+    state TEXT,
+    postal_code TEXT,
+    latitude DOUBLE PRECISION,
+    longitude DOUBLE PRECISION,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    UNIQUE (customer_id, name)
+);
+
+CREATE INDEX idx_customer_facilities_company ON customer_facilities(company_id);
+
+-- Where the trailer is: hooked to a truck, or dropped at a facility or a
+-- bare position. 'unknown' until the first drop or hook is recorded.
+ALTER TABLE trailers ADD COLUMN location_status TEXT NOT NULL DEFAULT 'unknown'
+    CHECK (location_status IN ('unknown', 'hooked', 'dropped'));
+ALTER TABLE trailers ADD COLUMN loaded BOOLEAN NOT NULL DEFAULT false;
+ALTER TABLE trailers ADD COLUMN truck_id UUID REFERENCES trucks(id);
+ALTER TABLE trailers ADD COLUMN facility_id UUID REFERENCES customer_facilities(id);
+ALTER TABLE trailers ADD COLUMN latitude DOUBLE PRECISION;
+ALTER TABLE trailers ADD COLUMN longitude DOUBLE PRECISION;
+ALTER TABLE trailers ADD COLUMN dropped_at TIMESTAMPTZ;
+ALTER TABLE trailers ADD COLUMN location_updated_at TIMESTAMPTZ;
+
+CREATE INDEX idx_trailers_dropped ON trailers(company_id, dropped_at) WHERE location_status = 'dropped';
+
+CREATE TABLE trailer_events (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    company_id UUID NOT NULL REFERENCES companies(id),
+    trailer_id UUID NOT NULL REFERENCES trailers(id),
+    event_type TEXT NOT NULL CHECK (event_type IN ('drop', 'hook')),
+    facility_id UUID REFERENCES customer_facilities(id),
+    load_id UUID REFERENCES loads(id) ON DELETE SET NULL,
+    driver_id UUID REFERENCES drivers(id),
+    truck_id UUID REFERENCES trucks(id),
+    loaded BOOLEAN NOT NULL,
+    latitude DOUBLE PRECISION,
+    longitude DOUBLE PRECISION,
+    note TEXT,
+    recorded_by UUID NOT NULL REFERENCES users(id),
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_trailer_events_trailer ON trailer_events(trailer_id, created_at);
+
+-- Days a dropped trailer may sit before it's flagged.
+ALTER TABLE companies ADD COLUMN trailer_idle_alert_days INTEGER NOT NULL DEFAULT 3
+    CHECK (trailer_idle_alert_days > 0);
+
+-- One alert per idle trailer per day, for as long as it keeps sitting.
+CREATE TABLE trailer_idle_alerts (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    company_id UUID NOT NULL REFERENCES companies(id),
+    trailer_id UUID NOT NULL REFERENCES trailers(id),
+    facility_id UUID REFERENCES customer_facilities(id),
+    alert_date DATE NOT NULL,
+    dropped_at TIMESTAMPTZ NOT NULL,
+    days_idle INTEGER NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    UNIQUE (trailer_id, alert_date)
+);
+
+CREATE INDEX idx_trailer_idle_alerts_company ON trailer_idle_alerts(company_id, alert_date);
@@ -0,0 +1,87 @@
+-- Incidents on the road and the insurance claims the accidents among them
+-- turn into: the incident record, the statement, photos and police report
+-- gathered for it, and the claim tracked with the insurer once the office
+-- closes the incident as a claim.
+
+CREATE TABLE incidents (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    company_id UUID NOT NULL REFERENCES companies(id),
+    incident_type TEXT NOT NULL
+        CHECK (incident_type IN ('accident', 'cargo_damage', 'injury', 'theft', 'other')),
+    driver_id UUID REFERENCES drivers(id),
+    truck_id UUID REFERENCES trucks(id),
+    trailer_id UUID REFERENCES trailers(id),
+    load_id UUID REFERENCES loads(id) ON DELETE SET NULL,
+    occurred_at TIMESTAMPTZ NOT NULL,
+    latitude DOUBLE PRECISION,
+    longitude DOUBLE PRECISION,
+    location_description TEXT,
+    description TEXT NOT NULL,
+    -- The driver's own account, in their words.
+    driver_statement TEXT,
+    police_report_number TEXT,
+    status TEXT NOT NULL DEFAULT 'open' CHECK (status IN ('open', 'closed')),
+    resolution TEXT CHECK (resolution IN ('claim', 'no_claim')),
+    resolution_note TEXT,
+    closed_by UUID REFERENCES users(id),
+    closed_at TIMESTAMPTZ,
+    reported_by UUID NOT NULL REFERENCES users(id),
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    CHECK ((status = 'closed') = (resolution IS NOT NULL))
+);
+
+CREATE INDEX idx_incidents_company ON incidents(company_id, status, occurred_at);
+CREATE INDEX idx_incidents_driver ON incidents(driver_id, occurred_at);
+
+-- Photos, police reports and signed statements filed against an incident.
+-- The files themselves are ordinary documents.
+CREATE TABLE incident_documents (
+    incident_id UUID NOT NULL REFERENCES incidents(id) ON DELETE CASCADE,
+    document_id UUID NOT NULL REFERENCES documents(id) ON DELETE CASCADE,
+    PRIMARY KEY (incident_id, document_id)
+);
+
+CREATE TABLE insurance_claims (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    company_id UUID NOT NULL REFERENCES companies(id),
+    incident_id UUID NOT NULL UNIQUE REFERENCES incidents(id),
+    insurer_name TEXT NOT NULL,
+    policy_number TEXT,
+    -- The insurer's own reference, once they've opened the claim.
+    insurer_claim_number TEXT,
+    status TEXT NOT NULL DEFAULT 'preparing'
+        CHECK (status IN ('preparing', 'submitted', 'open', 'closed', 'denied')),
+    -- What the insurer has set aside for the claim and paid on it so far.
+    reserve_amount NUMERIC(12, 2),
+    paid_amount NUMERIC(12, 2) NOT NULL DEFAULT 0,
+    -- What's being recovered from the at-fault party, and what has been.
+    subrogation_amount NUMERIC(12, 2),
+    subrogation_recovered NUMERIC(12, 2) NOT NULL DEFAULT 0,
+    -- The assembled submission packet and what it was missing when built.
+    packet_document_id UUID REFERENCES documents(id),
+    packet_missing TEXT[] NOT NULL DEFAULT '{}',
+    submitted_at TIMESTAMPTZ,
+    closed_at TIMESTAMPTZ,
+    created_by UUID NOT NULL REFERENCES users(id),
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_insurance_claims_company ON insurance_claims(company_id, status, created_at);
+
+-- Every change recorded against a claim, as the claim stood after it.
+CREATE TABLE insurance_claim_updates (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    claim_id UUID NOT NULL REFERENCES insurance_claims(id) ON DELETE CASCADE,
+    status TEXT NOT NULL,
+    reserve_amount NUMERIC(12, 2),
+    paid_amount NUMERIC(12, 2) NOT NULL,
+    subrogation_amount NUMERIC(12, 2),
+    subrogation_recovered NUMERIC(12, 2) NOT NULL,
+    note TEXT,
+    recorded_by UUID NOT NULL REFERENCES users(id),
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_insurance_claim_updates_claim ON insurance_claim_updates(claim_id, created_at);
@@ -0,0 +1,82 @@
+-- Yards: the company lots trucks and trailers park at, their numbered
+-- spots, what's in each yard now, the check-in, check-out and move
+-- events behind it, and the walks that confirm it against what's there.
+
+CREATE TABLE yards (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    company_id UUID NOT NULL REFERENCES companies(id),
+    name TEXT NOT NULL,
+    terminal_id UUID REFERENCES terminals(id),
+    address TEXT,
+    city TEXT,
+    state TEXT,
+    latitude DOUBLE PRECISION,
+    longitude DOUBLE PRECISION,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    UNIQUE (company_id, name)
+);
+
+CREATE TABLE yard_spots (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    yard_id UUID NOT NULL REFERENCES yards(id) ON DELETE CASCADE,
+    -- What's painted on the ground, e.g. "D14".
+    label TEXT NOT NULL,
+    spot_type TEXT NOT NULL DEFAULT 'any' CHECK (spot_type IN ('any', 'trailer', 'truck')),
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    UNIQUE (yard_id, label)
+);
+
+-- What's in the yard now: one row per truck or trailer, removed at
+-- check-out. A unit is in at most one yard and a spot holds one unit.
+CREATE TABLE yard_units (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    company_id UUID NOT NULL REFERENCES companies(id),
+    yard_id UUID NOT NULL REFERENCES yards(id),
+    spot_id UUID UNIQUE REFERENCES yard_spots(id) ON DELETE SET NULL,
+    unit_type TEXT NOT NULL CHECK (unit_type IN ('truck', 'trailer')),
+    truck_id UUID UNIQUE REFERENCES trucks(id),
+    trailer_id UUID UNIQUE REFERENCES trailers(id),
+    checked_in_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    checked_in_by UUID NOT NULL REFERENCES users(id),
+    -- The last yard walk that found it here.
+    last_seen_at TIMESTAMPTZ,
+    CHECK ((unit_type = 'truck') = (truck_id IS NOT NULL)),
+    CHECK ((unit_type = 'trailer') = (trailer_id IS NOT NULL))
+);
+
+CREATE INDEX idx_yard_units_yard ON yard_units(yard_id);
+
+CREATE TABLE yard_events (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    company_id UUID NOT NULL REFERENCES companies(id),
+    yard_id UUID NOT NULL REFERENCES yards(id),
+    spot_id UUID REFERENCES yard_spots(id) ON DELETE SET NULL,
+    unit_type TEXT NOT NULL,
+    truck_id UUID REFERENCES trucks(id),
+    trailer_id UUID REFERENCES trailers(id),
+    event_type TEXT NOT NULL CHECK (event_type IN ('check_in', 'check_out', 'move')),
+    note TEXT,
+    recorded_by UUID NOT NULL REFERENCES users(id),
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_yard_events_yard ON yard_events(yard_id, created_at);
+
+-- Each walk and what it turned up, as a YardWalkResult.
+CREATE TABLE yard_walks (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    company_id UUID NOT NULL REFERENCES companies(id),
+    yard_id UUID NOT NULL REFERENCES yards(id),
+    result JSONB NOT NULL,
+    walked_by UUID NOT NULL REFERENCES users(id),
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_yard_walks_yard ON yard_walks(yard_id, created_at);
+
+-- A trailer parked in a company yard is neither hooked nor dropped at a
+-- customer, and doesn't count toward the trailer pool's idle alerts.
+ALTER TABLE trailers DROP CONSTRAINT trailers_location_status_check;
+ALTER TABLE trailers ADD CONSTRAINT trailers_location_status_check
+    CHECK (location_status IN ('unknown', 'hooked', 'dropped', 'in_yard'));
@@ -0,0 +1,39 @@
+-- Legal holds: loads, drivers and documents that litigation or a DOT audit
+-- needs kept exactly as they are, with who placed and released each hold.
+
+CREATE TABLE legal_holds (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    company_id UUID NOT NULL REFERENCES companies(id),
+    subject_type TEXT NOT NULL CHECK (subject_type IN ('load', 'driver', 'document')),
+    subject_id UUID NOT NULL,
+    hold_type TEXT NOT NULL CHECK (hold_type IN ('litigation', 'dot_audit', 'other')),
+    -- The case, claim or audit the hold is for.
+    matter TEXT NOT NULL,
+    reason TEXT,
+    placed_by UUID NOT NULL REFERENCES users(id),
+    placed_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    released_by UUID REFERENCES users(id),
+    released_at TIMESTAMPTZ,
+    release_reason TEXT
+);
+
+CREATE INDEX idx_legal_holds_subject ON legal_holds(subject_type, subject_id) WHERE released_at IS NULL;
+CREATE INDEX idx_legal_holds_company ON legal_holds(company_id, placed_at);
+
+-- Append-only: one row each time a hold is placed or released.
+CREATE TABLE legal_hold_events (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    hold_id UUID NOT NULL REFERENCES legal_holds(id),
+    action TEXT NOT NULL CHECK (action IN ('placed', 'released')),
+    actor_id UUID NOT NULL REFERENCES users(id),
+    note TEXT,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_legal_hold_events_hold ON legal_hold_events(hold_id, created_at);
+
+-- Set while any hold on the record is active. Retention and archival work
+-- skips flagged records; a document also falls under its load's hold.
+ALTER TABLE loads ADD COLUMN legal_hold BOOLEAN NOT NULL DEFAULT false;
+ALTER TABLE drivers ADD COLUMN legal_hold BOOLEAN NOT NULL DEFAULT false;
+ALTER TABLE documents ADD COLUMN legal_hold BOOLEAN NOT NULL DEFAULT false;
@@ -0,0 +1,32 @@
+-- Cross-dock and transload: a shipment split into segment loads that meet
+-- at a dock, and the freight sitting on the dock between them.
+
+-- The shipment a segment belongs to. Shipments are one level deep: a
+-- segment is never itself a parent.
+ALTER TABLE loads ADD COLUMN parent_load_id UUID REFERENCES loads(id);
+
+CREATE INDEX idx_loads_parent ON loads(parent_load_id) WHERE parent_load_id IS NOT NULL;
+
+-- Freight received at a terminal's dock, then allocated to the outbound
+-- segment that carries it on and shipped when that segment picks up.
+CREATE TABLE dock_inventory (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    company_id UUID NOT NULL REFERENCES companies(id),
+    terminal_id UUID NOT NULL REFERENCES terminals(id),
+    shipment_load_id UUID NOT NULL REFERENCES loads(id),
+    -- The load it arrived on; the shipment itself or one of its segments.
+    inbound_load_id UUID NOT NULL REFERENCES loads(id),
+    outbound_load_id UUID REFERENCES loads(id),
+    description TEXT NOT NULL,
+    pieces INTEGER NOT NULL CHECK (pieces > 0),
+    weight_lbs INTEGER CHECK (weight_lbs >= 0),
+    status TEXT NOT NULL DEFAULT 'on_dock' CHECK (status IN ('on_dock', 'allocated', 'shipped')),
+    received_by UUID NOT NULL REFERENCES users(id),
+    received_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    shipped_at TIMESTAMPTZ,
+    CHECK ((status = 'on_dock') = (outbound_load_id IS NULL))
+);
+
+CREATE INDEX idx_dock_inventory_terminal ON dock_inventory(terminal_id, status);
+CREATE INDEX idx_dock_inventory_shipment ON dock_inventory(shipment_load_id);
+CREATE INDEX idx_dock_inventory_outbound ON dock_inventory(outbound_load_id) WHERE outbound_load_id IS NOT NULL;
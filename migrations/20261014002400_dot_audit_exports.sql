@@ -0,0 +1,46 @@
+-- DOT compliance audit exports, and the maintenance log they draw on
+-- alongside drivers, time-clock hours and the accident register.
+
+-- Inspections, preventive maintenance and repairs, per truck or trailer.
+CREATE TABLE maintenance_records (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    company_id UUID NOT NULL REFERENCES companies(id),
+    truck_id UUID REFERENCES trucks(id),
+    trailer_id UUID REFERENCES trailers(id),
+    performed_on DATE NOT NULL,
+    maintenance_type TEXT NOT NULL
+        CHECK (maintenance_type IN ('annual_inspection', 'inspection', 'preventive', 'repair')),
+    description TEXT NOT NULL,
+    odometer_miles INTEGER CHECK (odometer_miles >= 0),
+    vendor TEXT,
+    cost NUMERIC(12, 2) CHECK (cost >= 0),
+    recorded_by UUID NOT NULL REFERENCES users(id),
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    CHECK ((truck_id IS NULL) <> (trailer_id IS NULL))
+);
+
+CREATE INDEX idx_maintenance_records_company ON maintenance_records(company_id, performed_on);
+CREATE INDEX idx_maintenance_records_truck ON maintenance_records(truck_id, performed_on) WHERE truck_id IS NOT NULL;
+CREATE INDEX idx_maintenance_records_trailer ON maintenance_records(trailer_id, performed_on) WHERE trailer_id IS NOT NULL;
+
+-- Requested from the office and built by the export job. The finished
+-- archive is an ordinary document.
+CREATE TABLE dot_audit_exports (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    company_id UUID NOT NULL REFERENCES companies(id),
+    period_start DATE NOT NULL,
+    period_end DATE NOT NULL,
+    status TEXT NOT NULL DEFAULT 'queued' CHECK (status IN ('queued', 'running', 'completed', 'failed')),
+    -- [{section, records, note}] as the archive's manifest lists them.
+    sections JSONB,
+    document_id UUID REFERENCES documents(id),
+    error TEXT,
+    requested_by UUID NOT NULL REFERENCES users(id),
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    started_at TIMESTAMPTZ,
+    completed_at TIMESTAMPTZ,
+    CHECK (period_end >= period_start)
+);
+
+CREATE INDEX idx_dot_audit_exports_company ON dot_audit_exports(company_id, created_at);
+CREATE INDEX idx_dot_audit_exports_pending ON dot_audit_exports(created_at) WHERE status IN ('queued', 'running');
@@ -0,0 +1,36 @@
+-- LTL consolidation: several customers' shipments riding on one load,
+-- each rated and invoiced on its own while the load's costs are shared.
+
+CREATE TABLE load_shipments (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    company_id UUID NOT NULL REFERENCES companies(id),
+    load_id UUID NOT NULL REFERENCES loads(id) ON DELETE CASCADE,
+    customer_id UUID NOT NULL REFERENCES customers(id),
+    reference_number TEXT,
+    description TEXT,
+    pieces INTEGER NOT NULL CHECK (pieces > 0),
+    weight_lbs INTEGER NOT NULL CHECK (weight_lbs > 0),
+    customer_rate NUMERIC(12, 2) NOT NULL CHECK (customer_rate >= 0),
+    shipper_name TEXT,
+    consignee_name TEXT,
+    origin_city TEXT,
+    origin_state TEXT,
+    destination_city TEXT,
+    destination_state TEXT,
+    created_by UUID NOT NULL REFERENCES users(id),
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_load_shipments_load ON load_shipments(load_id);
+CREATE INDEX idx_load_shipments_customer ON load_shipments(customer_id);
+
+-- A shipment is invoiced once; voiding the invoice frees it to be billed
+-- again.
+ALTER TABLE invoices ADD COLUMN shipment_id UUID REFERENCES load_shipments(id) ON DELETE SET NULL;
+
+CREATE UNIQUE INDEX idx_invoices_shipment ON invoices(shipment_id) WHERE shipment_id IS NOT NULL AND status <> 'void';
+
+-- What the trailer can legally carry, where it differs from the usual
+-- payload for its equipment type.
+ALTER TABLE trailers ADD COLUMN max_payload_lbs INTEGER CHECK (max_payload_lbs > 0);
@@ -0,0 +1,56 @@
+-- Intermodal: drayage and rail loads, the container and chassis they move,
+-- the railroad's billing, port and ramp appointments, and the free time a
+-- container has before per diem and demurrage start.
+
+UPDATE loads SET mode = 'truckload' WHERE mode NOT IN ('truckload', 'ltl', 'drayage', 'rail');
+ALTER TABLE loads ADD CONSTRAINT loads_mode_check CHECK (mode IN ('truckload', 'ltl', 'drayage', 'rail'));
+
+-- One row per drayage or rail load.
+CREATE TABLE load_intermodal (
+    load_id UUID PRIMARY KEY REFERENCES loads(id) ON DELETE CASCADE,
+    company_id UUID NOT NULL REFERENCES companies(id),
+    -- ISO 6346, e.g. MSCU1234566.
+    container_number TEXT CHECK (container_number ~ '^[A-Z]{4}[0-9]{7}$'),
+    container_size TEXT CHECK (container_size IN ('20', '40', '40hc', '45', '53')),
+    steamship_line TEXT,
+    booking_number TEXT,
+    master_bill_of_lading TEXT,
+    chassis_number TEXT,
+    chassis_provider TEXT,
+    rail_carrier TEXT,
+    rail_waybill_number TEXT,
+    rail_billing_reference TEXT,
+    -- Storage at the port or ramp is charged after this day.
+    last_free_day DATE,
+    -- Out the gate loaded, and back in empty. Per diem runs between them
+    -- once the free days are used.
+    container_out_at TIMESTAMPTZ,
+    container_returned_at TIMESTAMPTZ,
+    per_diem_free_days INTEGER NOT NULL DEFAULT 4 CHECK (per_diem_free_days >= 0),
+    per_diem_rate NUMERIC(12, 2) CHECK (per_diem_rate >= 0),
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    CHECK (container_returned_at IS NULL OR container_out_at IS NOT NULL),
+    CHECK (container_returned_at IS NULL OR container_returned_at >= container_out_at)
+);
+
+CREATE INDEX idx_load_intermodal_out ON load_intermodal(company_id) WHERE container_out_at IS NOT NULL AND container_returned_at IS NULL;
+
+CREATE TABLE intermodal_appointments (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    company_id UUID NOT NULL REFERENCES companies(id),
+    load_id UUID NOT NULL REFERENCES loads(id) ON DELETE CASCADE,
+    appointment_type TEXT NOT NULL
+        CHECK (appointment_type IN ('port_pickup', 'port_return', 'ramp_pickup', 'ramp_drop')),
+    facility_name TEXT NOT NULL,
+    window_start TIMESTAMPTZ NOT NULL,
+    window_end TIMESTAMPTZ NOT NULL,
+    confirmation_number TEXT,
+    status TEXT NOT NULL DEFAULT 'scheduled' CHECK (status IN ('scheduled', 'completed', 'missed', 'cancelled')),
+    created_by UUID NOT NULL REFERENCES users(id),
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    CHECK (window_end >= window_start)
+);
+
+CREATE INDEX idx_intermodal_appointments_load ON intermodal_appointments(load_id, window_start);
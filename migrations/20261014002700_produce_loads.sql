@@ -0,0 +1,15 @@
+-- Produce loads: where the crop was harvested for traceability, and the
+-- serial of the temperature recorder placed in the trailer at pickup.
+
+ALTER TABLE loads ADD COLUMN commodity_type TEXT NOT NULL DEFAULT 'general'
+    CHECK (commodity_type IN ('general', 'produce'));
+
+-- Required on produce loads; the grower or packing shed and the field's
+-- lot, as they appear on the shipper's manifest.
+ALTER TABLE loads ADD COLUMN harvest_location_name TEXT;
+ALTER TABLE loads ADD COLUMN harvest_city TEXT;
+ALTER TABLE loads ADD COLUMN harvest_state TEXT;
+ALTER TABLE loads ADD COLUMN harvest_date DATE;
+ALTER TABLE loads ADD COLUMN harvest_lot_number TEXT;
+
+ALTER TABLE load_stops ADD COLUMN temperature_recorder_serial TEXT;
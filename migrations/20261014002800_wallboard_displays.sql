@@ -0,0 +1,21 @@
+-- Office TV wallboards: each display holds a token of its own instead of
+-- a login, and shows the widgets it was set up with.
+
+CREATE TABLE wallboard_displays (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    company_id UUID NOT NULL REFERENCES companies(id),
+    name TEXT NOT NULL,
+    token_hash TEXT NOT NULL UNIQUE,
+    -- Which of the feed's widgets the display shows, in order.
+    widgets TEXT[] NOT NULL,
+    -- How many hot loads fit on screen, and how long each page stays up.
+    page_size INTEGER NOT NULL DEFAULT 8 CHECK (page_size BETWEEN 1 AND 50),
+    rotate_seconds INTEGER NOT NULL DEFAULT 15 CHECK (rotate_seconds BETWEEN 5 AND 300),
+    created_by UUID NOT NULL REFERENCES users(id),
+    last_seen_at TIMESTAMPTZ,
+    revoked_at TIMESTAMPTZ,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_wallboard_displays_company ON wallboard_displays(company_id);
@@ -0,0 +1,47 @@
+-- Reefer monitoring: the range a load must be kept in, trailer
+-- temperature readings pushed by telematics units, and the excursions
+-- out of range while a load is in transit.
+
+ALTER TABLE loads ADD COLUMN temperature_min_f DOUBLE PRECISION;
+ALTER TABLE loads ADD COLUMN temperature_max_f DOUBLE PRECISION;
+ALTER TABLE loads ADD CONSTRAINT loads_temperature_range_check
+    CHECK ((temperature_min_f IS NULL) = (temperature_max_f IS NULL) AND temperature_min_f <= temperature_max_f);
+
+-- Append-only. A unit re-sending a batch doesn't duplicate readings.
+CREATE TABLE trailer_temperature_readings (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    company_id UUID NOT NULL REFERENCES companies(id),
+    trailer_id UUID NOT NULL REFERENCES trailers(id),
+    -- The load the trailer was on when the reading was received.
+    load_id UUID REFERENCES loads(id),
+    recorded_at TIMESTAMPTZ NOT NULL,
+    temperature_f DOUBLE PRECISION NOT NULL,
+    setpoint_f DOUBLE PRECISION,
+    latitude DOUBLE PRECISION,
+    longitude DOUBLE PRECISION,
+    received_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    UNIQUE (trailer_id, recorded_at)
+);
+
+CREATE INDEX idx_temperature_readings_load ON trailer_temperature_readings(load_id, recorded_at) WHERE load_id IS NOT NULL;
+
+-- A run of in-transit readings outside the load's range. Open until a
+-- reading comes back in range.
+CREATE TABLE temperature_excursions (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    company_id UUID NOT NULL REFERENCES companies(id),
+    load_id UUID NOT NULL REFERENCES loads(id),
+    trailer_id UUID NOT NULL REFERENCES trailers(id),
+    required_min_f DOUBLE PRECISION NOT NULL,
+    required_max_f DOUBLE PRECISION NOT NULL,
+    started_at TIMESTAMPTZ NOT NULL,
+    ended_at TIMESTAMPTZ,
+    lowest_f DOUBLE PRECISION NOT NULL,
+    highest_f DOUBLE PRECISION NOT NULL,
+    readings INTEGER NOT NULL DEFAULT 1,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+-- At most one open excursion per load.
+CREATE UNIQUE INDEX idx_temperature_excursions_open ON temperature_excursions(load_id) WHERE ended_at IS NULL;
+CREATE INDEX idx_temperature_excursions_company ON temperature_excursions(company_id, started_at);
@@ -0,0 +1,28 @@
+-- Hazardous materials: what a hazmat load carries and the placards and
+-- emergency contact it travels with, the CDL endorsements that let a
+-- driver haul it, and whether a carrier's authority covers it.
+
+ALTER TABLE loads ADD COLUMN hazmat BOOLEAN NOT NULL DEFAULT false;
+-- UN or NA identification number, e.g. UN1203.
+ALTER TABLE loads ADD COLUMN hazmat_un_number TEXT;
+ALTER TABLE loads ADD COLUMN hazmat_proper_shipping_name TEXT;
+ALTER TABLE loads ADD COLUMN hazmat_class TEXT;
+ALTER TABLE loads ADD COLUMN hazmat_packing_group TEXT CHECK (hazmat_packing_group IN ('I', 'II', 'III'));
+ALTER TABLE loads ADD COLUMN hazmat_placards TEXT[] NOT NULL DEFAULT '{}';
+-- Who answers the 24-hour emergency response number: the shipper, or the
+-- registrant of an emergency response contract.
+ALTER TABLE loads ADD COLUMN hazmat_emergency_contact TEXT;
+ALTER TABLE loads ADD COLUMN hazmat_emergency_phone TEXT;
+ALTER TABLE loads ADD CONSTRAINT loads_hazmat_check CHECK (
+    NOT hazmat OR (hazmat_un_number IS NOT NULL AND hazmat_proper_shipping_name IS NOT NULL
+                   AND hazmat_class IS NOT NULL AND hazmat_emergency_phone IS NOT NULL)
+);
+
+-- Letters endorsed on the CDL, e.g. {H, N, T}. The hazmat endorsement
+-- lapses with its TSA threat assessment, which renews apart from the CDL.
+ALTER TABLE drivers ADD COLUMN cdl_endorsements TEXT[] NOT NULL DEFAULT '{}';
+ALTER TABLE drivers ADD COLUMN hazmat_endorsement_expiry DATE;
+
+-- Taken from the FMCSA census when the carrier's authority is checked.
+ALTER TABLE carriers ADD COLUMN hazmat_authorized BOOLEAN NOT NULL DEFAULT false;
+ALTER TABLE carriers ADD COLUMN hazmat_verified_at TIMESTAMPTZ;
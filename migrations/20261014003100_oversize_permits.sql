@@ -0,0 +1,31 @@
+-- Oversize/overweight permits: the states an OD load's route needs a
+-- permit from, and the permits issued for the load and the truck pulling
+-- it. A load can't be dispatched until every state is covered.
+
+ALTER TABLE loads ADD COLUMN oversize_overweight BOOLEAN NOT NULL DEFAULT false;
+ALTER TABLE loads ADD COLUMN permit_states TEXT[] NOT NULL DEFAULT '{}';
+
+CREATE TABLE load_permits (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    company_id UUID NOT NULL REFERENCES companies(id),
+    load_id UUID NOT NULL REFERENCES loads(id) ON DELETE CASCADE,
+    -- The power unit named on the permit; null for one issued to a
+    -- carrier's truck we don't keep.
+    truck_id UUID REFERENCES trucks(id),
+    state TEXT NOT NULL,
+    permit_number TEXT NOT NULL,
+    permit_type TEXT NOT NULL
+        CHECK (permit_type IN ('oversize', 'overweight', 'oversize_overweight', 'superload')),
+    valid_from DATE NOT NULL,
+    valid_to DATE NOT NULL,
+    -- The routing the state approved, as written on the permit.
+    route TEXT NOT NULL,
+    cost NUMERIC(12, 2) NOT NULL DEFAULT 0 CHECK (cost >= 0),
+    document_id UUID REFERENCES documents(id),
+    created_by UUID NOT NULL REFERENCES users(id),
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    CHECK (valid_to >= valid_from),
+    UNIQUE (company_id, state, permit_number)
+);
+
+CREATE INDEX idx_load_permits_load ON load_permits(load_id);
@@ -0,0 +1,60 @@
+-- Tolls: the estimate priced for a load's route at planning time, and the
+-- transponder statements imported afterwards, each charge matched to the
+-- truck carrying the tag and the load it was running.
+
+-- The E-ZPass or PrePass tag mounted in the truck.
+ALTER TABLE trucks ADD COLUMN toll_transponder_number TEXT;
+
+CREATE UNIQUE INDEX idx_trucks_toll_transponder ON trucks(company_id, toll_transponder_number)
+    WHERE toll_transponder_number IS NOT NULL;
+
+-- The latest estimate; re-estimating replaces it.
+CREATE TABLE load_toll_estimates (
+    load_id UUID PRIMARY KEY REFERENCES loads(id) ON DELETE CASCADE,
+    company_id UUID NOT NULL REFERENCES companies(id),
+    provider TEXT NOT NULL,
+    amount NUMERIC(12, 2) NOT NULL CHECK (amount >= 0),
+    miles DOUBLE PRECISION NOT NULL,
+    estimated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE TABLE toll_statements (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    company_id UUID NOT NULL REFERENCES companies(id),
+    source TEXT NOT NULL CHECK (source IN ('ezpass', 'prepass', 'other')),
+    file_name TEXT NOT NULL,
+    -- The file as uploaded.
+    document_id UUID REFERENCES documents(id),
+    transactions INTEGER NOT NULL DEFAULT 0,
+    -- Rows already imported from an earlier statement.
+    duplicates INTEGER NOT NULL DEFAULT 0,
+    total_amount NUMERIC(12, 2) NOT NULL DEFAULT 0,
+    imported_by UUID NOT NULL REFERENCES users(id),
+    imported_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_toll_statements_company ON toll_statements(company_id, imported_at);
+
+-- One charge or credit. Matched charges count toward the load's cost.
+CREATE TABLE toll_transactions (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    company_id UUID NOT NULL REFERENCES companies(id),
+    statement_id UUID NOT NULL REFERENCES toll_statements(id),
+    transponder_number TEXT NOT NULL,
+    occurred_at TIMESTAMPTZ NOT NULL,
+    agency TEXT NOT NULL DEFAULT '',
+    plaza TEXT NOT NULL DEFAULT '',
+    -- Negative for credits and adjustments.
+    amount NUMERIC(12, 2) NOT NULL,
+    truck_id UUID REFERENCES trucks(id),
+    load_id UUID REFERENCES loads(id) ON DELETE SET NULL,
+    -- Set when someone assigned the load by hand; automatic matching
+    -- leaves these alone.
+    matched_by UUID REFERENCES users(id),
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    UNIQUE (company_id, transponder_number, occurred_at, plaza, amount)
+);
+
+CREATE INDEX idx_toll_transactions_statement ON toll_transactions(statement_id);
+CREATE INDEX idx_toll_transactions_load ON toll_transactions(load_id) WHERE load_id IS NOT NULL;
+CREATE INDEX idx_toll_transactions_unmatched ON toll_transactions(company_id, occurred_at) WHERE load_id IS NULL;
@@ -0,0 +1,41 @@
+-- Carrier certificates of insurance: each policy's coverage and dates,
+-- entered by hand or pulled from a certificate monitoring service, and the
+-- minimums a carrier must carry before a load is tendered to it.
+
+-- Zero means the coverage isn't required.
+ALTER TABLE companies ADD COLUMN min_auto_liability NUMERIC(14, 2) NOT NULL DEFAULT 1000000 CHECK (min_auto_liability >= 0);
+ALTER TABLE companies ADD COLUMN min_cargo_coverage NUMERIC(14, 2) NOT NULL DEFAULT 100000 CHECK (min_cargo_coverage >= 0);
+ALTER TABLE companies ADD COLUMN min_general_liability NUMERIC(14, 2) NOT NULL DEFAULT 1000000 CHECK (min_general_liability >= 0);
+
+-- When the monitoring service was last asked for the carrier's
+-- certificates.
+ALTER TABLE carriers ADD COLUMN insurance_checked_at TIMESTAMPTZ;
+
+CREATE TABLE carrier_insurance_certificates (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    company_id UUID NOT NULL REFERENCES companies(id),
+    carrier_id UUID NOT NULL REFERENCES carriers(id) ON DELETE CASCADE,
+    coverage_type TEXT NOT NULL CHECK (coverage_type IN ('auto_liability', 'cargo', 'general_liability')),
+    insurer_name TEXT NOT NULL,
+    policy_number TEXT NOT NULL,
+    coverage_amount NUMERIC(14, 2) NOT NULL CHECK (coverage_amount > 0),
+    effective_on DATE NOT NULL,
+    expires_on DATE NOT NULL,
+    document_id UUID REFERENCES documents(id),
+    source TEXT NOT NULL DEFAULT 'manual' CHECK (source IN ('manual', 'provider')),
+    -- The monitoring service's id for certificates it reported.
+    provider_reference TEXT,
+    -- Cancelled mid-term; a cancelled policy covers nothing.
+    cancelled_at TIMESTAMPTZ,
+    -- Dispatchers were warned of the coming lapse.
+    expiry_warned_at TIMESTAMPTZ,
+    created_by UUID REFERENCES users(id),
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    CHECK (expires_on >= effective_on),
+    UNIQUE (carrier_id, coverage_type, policy_number, effective_on)
+);
+
+CREATE INDEX idx_carrier_insurance_carrier ON carrier_insurance_certificates(carrier_id, coverage_type);
+CREATE INDEX idx_carrier_insurance_expiring ON carrier_insurance_certificates(expires_on)
+    WHERE cancelled_at IS NULL AND expiry_warned_at IS NULL;
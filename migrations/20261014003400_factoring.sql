@@ -0,0 +1,55 @@
+-- Invoice factoring: delivered-load invoices sold to a factoring company,
+-- the advance it funds, and the reserve it releases once the customer
+-- pays, less its fee.
+
+-- One agreement per company; the terms price submissions until the
+-- factor reports its own figures.
+CREATE TABLE factoring_agreements (
+    company_id UUID PRIMARY KEY REFERENCES companies(id),
+    factor_name TEXT NOT NULL,
+    -- Our account number with the factor.
+    client_reference TEXT NOT NULL,
+    advance_percent NUMERIC(5, 2) NOT NULL CHECK (advance_percent > 0 AND advance_percent <= 100),
+    fee_percent NUMERIC(5, 2) NOT NULL CHECK (fee_percent >= 0 AND fee_percent < 100),
+    active BOOLEAN NOT NULL DEFAULT TRUE,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE TABLE factoring_submissions (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    company_id UUID NOT NULL REFERENCES companies(id),
+    invoice_id UUID NOT NULL REFERENCES invoices(id),
+    load_id UUID NOT NULL REFERENCES loads(id),
+    status TEXT NOT NULL DEFAULT 'submitted'
+        CHECK (status IN ('submitted', 'funded', 'rejected', 'reserve_released')),
+    invoice_amount NUMERIC(12, 2) NOT NULL,
+    advance_amount NUMERIC(12, 2) NOT NULL CHECK (advance_amount >= 0),
+    fee_amount NUMERIC(12, 2) NOT NULL CHECK (fee_amount >= 0),
+    -- Held back by the factor until the customer pays.
+    reserve_amount NUMERIC(12, 2) NOT NULL,
+    reserve_released_amount NUMERIC(12, 2) NOT NULL DEFAULT 0,
+    -- The factor's id for the submission; unset when it was sent outside
+    -- the API and is tracked by hand.
+    provider_reference TEXT,
+    -- The POD bundle sent with the invoice.
+    document_ids UUID[] NOT NULL DEFAULT '{}',
+    rejection_reason TEXT,
+    submitted_by UUID NOT NULL REFERENCES users(id),
+    submitted_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    funded_at TIMESTAMPTZ,
+    reserve_released_at TIMESTAMPTZ,
+    rejected_at TIMESTAMPTZ,
+    last_checked_at TIMESTAMPTZ,
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+-- An invoice is factored once; a rejected submission frees it to be sent
+-- again.
+CREATE UNIQUE INDEX idx_factoring_submissions_invoice ON factoring_submissions(invoice_id) WHERE status <> 'rejected';
+CREATE INDEX idx_factoring_submissions_company ON factoring_submissions(company_id, submitted_at);
+CREATE INDEX idx_factoring_submissions_open ON factoring_submissions(last_checked_at NULLS FIRST)
+    WHERE status IN ('submitted', 'funded') AND provider_reference IS NOT NULL;
+
+-- What the factor kept of a factored invoice's face value.
+ALTER TABLE invoices ADD COLUMN factoring_fee NUMERIC(12, 2) NOT NULL DEFAULT 0;
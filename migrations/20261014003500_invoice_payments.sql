@@ -0,0 +1,39 @@
+-- Customer payments by card or ACH through the payment processor: one row
+-- per payment intent, applied to the invoice once the processor reports
+-- it settled.
+
+-- Added to card payments to cover processing fees; card network rules
+-- cap it at 3%. ACH payments are never surcharged.
+ALTER TABLE companies ADD COLUMN card_surcharge_percent NUMERIC(5, 2) NOT NULL DEFAULT 0
+    CHECK (card_surcharge_percent >= 0 AND card_surcharge_percent <= 3);
+
+CREATE TABLE invoice_payments (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    company_id UUID NOT NULL REFERENCES companies(id),
+    invoice_id UUID NOT NULL REFERENCES invoices(id),
+    method TEXT NOT NULL CHECK (method IN ('card', 'ach')),
+    -- Applied to the invoice; the surcharge is charged on top.
+    amount NUMERIC(12, 2) NOT NULL CHECK (amount > 0),
+    surcharge_amount NUMERIC(12, 2) NOT NULL DEFAULT 0 CHECK (surcharge_amount >= 0),
+    status TEXT NOT NULL DEFAULT 'pending'
+        CHECK (status IN ('pending', 'processing', 'succeeded', 'failed', 'cancelled')),
+    -- The processor's payment intent.
+    processor_reference TEXT UNIQUE,
+    failure_reason TEXT,
+    created_by UUID NOT NULL REFERENCES users(id),
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    settled_at TIMESTAMPTZ,
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_invoice_payments_invoice ON invoice_payments(invoice_id, created_at);
+
+-- Webhook deliveries already handled; the processor retries and may send
+-- an event more than once.
+CREATE TABLE payment_webhook_events (
+    event_id TEXT PRIMARY KEY,
+    company_id UUID NOT NULL REFERENCES companies(id),
+    event_type TEXT NOT NULL,
+    payment_id UUID REFERENCES invoice_payments(id),
+    received_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
@@ -0,0 +1,45 @@
+-- Cash application: checks, ACH and wires received from customers, each
+-- applied across one or more of the customer's invoices. Whatever isn't
+-- applied stays on the payment as a credit for later invoices.
+
+CREATE TABLE customer_payments (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    company_id UUID NOT NULL REFERENCES companies(id),
+    customer_id UUID NOT NULL REFERENCES customers(id),
+    method TEXT NOT NULL CHECK (method IN ('check', 'ach', 'wire')),
+    -- Check number or bank trace number.
+    reference_number TEXT,
+    amount NUMERIC(12, 2) NOT NULL CHECK (amount > 0),
+    unapplied_amount NUMERIC(12, 2) NOT NULL CHECK (unapplied_amount >= 0 AND unapplied_amount <= amount),
+    received_on DATE NOT NULL,
+    memo TEXT,
+    recorded_by UUID NOT NULL REFERENCES users(id),
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    -- Bounced or entered in error; its applications are reversed.
+    voided_at TIMESTAMPTZ,
+    void_reason TEXT
+);
+
+CREATE INDEX idx_customer_payments_customer ON customer_payments(customer_id, received_on);
+CREATE INDEX idx_customer_payments_credit ON customer_payments(customer_id) WHERE unapplied_amount > 0 AND voided_at IS NULL;
+
+-- The same check can't be recorded twice.
+CREATE UNIQUE INDEX idx_customer_payments_reference ON customer_payments(customer_id, method, reference_number)
+    WHERE reference_number IS NOT NULL AND voided_at IS NULL;
+
+CREATE TABLE payment_applications (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    company_id UUID NOT NULL REFERENCES companies(id),
+    payment_id UUID NOT NULL REFERENCES customer_payments(id),
+    invoice_id UUID NOT NULL REFERENCES invoices(id),
+    amount NUMERIC(12, 2) NOT NULL CHECK (amount > 0),
+    applied_by UUID NOT NULL REFERENCES users(id),
+    applied_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    -- Taken back off the invoice, returning the amount to the payment's
+    -- credit.
+    reversed_at TIMESTAMPTZ,
+    reversed_by UUID REFERENCES users(id)
+);
+
+CREATE INDEX idx_payment_applications_payment ON payment_applications(payment_id);
+CREATE INDEX idx_payment_applications_invoice ON payment_applications(invoice_id);
@@ -0,0 +1,30 @@
+-- Carrier payables: when each carrier invoice falls due under the
+-- carrier's terms, the pay run an approved invoice is scheduled into, and
+-- the payment that settled it.
+
+-- Days from the carrier's invoice date to payment.
+ALTER TABLE carriers ADD COLUMN payment_terms INTEGER NOT NULL DEFAULT 30 CHECK (payment_terms >= 0);
+
+-- The weekday carriers are paid on, Monday = 1. Unset pays each invoice
+-- on its due date.
+ALTER TABLE companies ADD COLUMN carrier_pay_weekday SMALLINT CHECK (carrier_pay_weekday BETWEEN 1 AND 7);
+
+ALTER TABLE carrier_invoices ADD COLUMN due_date DATE;
+UPDATE carrier_invoices ci SET due_date = ci.invoice_date + c.payment_terms
+FROM carriers c WHERE c.id = ci.carrier_id;
+ALTER TABLE carrier_invoices ALTER COLUMN due_date SET NOT NULL;
+
+-- The pay run the invoice goes out in; set on approval and moved by hand
+-- for quick pay or a hold.
+ALTER TABLE carrier_invoices ADD COLUMN scheduled_pay_date DATE;
+UPDATE carrier_invoices SET scheduled_pay_date = due_date WHERE status = 'approved';
+
+-- The day the payment went out.
+ALTER TABLE carrier_invoices ADD COLUMN paid_on DATE;
+ALTER TABLE carrier_invoices ADD COLUMN payment_method TEXT CHECK (payment_method IN ('check', 'ach', 'wire'));
+-- Check number or bank trace number.
+ALTER TABLE carrier_invoices ADD COLUMN payment_reference TEXT;
+ALTER TABLE carrier_invoices ADD COLUMN paid_by UUID REFERENCES users(id);
+
+CREATE INDEX idx_carrier_invoices_payable ON carrier_invoices(company_id, scheduled_pay_date)
+    WHERE status = 'approved' AND paid_on IS NULL;
@@ -0,0 +1,39 @@
+-- Quick pay: a carrier gives up a percentage of an approved invoice to be
+-- paid within days instead of on its terms. Requests wait for accounting
+-- to approve them before the payable moves.
+
+-- Unset when the company doesn't offer quick pay.
+ALTER TABLE companies ADD COLUMN quick_pay_fee_percent NUMERIC(5, 2)
+    CHECK (quick_pay_fee_percent >= 0 AND quick_pay_fee_percent < 100);
+-- How soon an approved quick-pay request is paid.
+ALTER TABLE companies ADD COLUMN quick_pay_days INTEGER NOT NULL DEFAULT 2 CHECK (quick_pay_days >= 0);
+
+-- A rate negotiated with the carrier in place of the company's.
+ALTER TABLE carriers ADD COLUMN quick_pay_fee_percent NUMERIC(5, 2)
+    CHECK (quick_pay_fee_percent >= 0 AND quick_pay_fee_percent < 100);
+
+-- Taken off the invoice total once quick pay is approved.
+ALTER TABLE carrier_invoices ADD COLUMN quick_pay_fee NUMERIC(12, 2) NOT NULL DEFAULT 0;
+
+CREATE TABLE carrier_quick_pay_requests (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    company_id UUID NOT NULL REFERENCES companies(id),
+    carrier_invoice_id UUID NOT NULL REFERENCES carrier_invoices(id),
+    carrier_id UUID NOT NULL REFERENCES carriers(id),
+    status TEXT NOT NULL DEFAULT 'requested' CHECK (status IN ('requested', 'approved', 'declined')),
+    fee_percent NUMERIC(5, 2) NOT NULL,
+    fee_amount NUMERIC(12, 2) NOT NULL CHECK (fee_amount >= 0),
+    -- The invoice total less the fee.
+    net_amount NUMERIC(12, 2) NOT NULL,
+    requested_by UUID NOT NULL REFERENCES users(id),
+    requested_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    decided_by UUID REFERENCES users(id),
+    decided_at TIMESTAMPTZ,
+    decline_reason TEXT
+);
+
+-- A declined request frees the invoice to be asked for again.
+CREATE UNIQUE INDEX idx_quick_pay_requests_invoice ON carrier_quick_pay_requests(carrier_invoice_id)
+    WHERE status <> 'declined';
+CREATE INDEX idx_quick_pay_requests_pending ON carrier_quick_pay_requests(company_id, requested_at)
+    WHERE status = 'requested';
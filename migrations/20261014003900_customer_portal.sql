@@ -0,0 +1,45 @@
+-- Customer self-service portal: user accounts that sign in for one of the
+-- company's customers, and the shipment requests they send in for
+-- dispatch to book.
+
+-- Set on customer portal accounts: the customer they act for.
+ALTER TABLE users ADD COLUMN customer_id UUID REFERENCES customers(id);
+
+CREATE INDEX idx_users_customer ON users(customer_id) WHERE customer_id IS NOT NULL;
+
+CREATE TABLE shipment_requests (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    company_id UUID NOT NULL REFERENCES companies(id),
+    customer_id UUID NOT NULL REFERENCES customers(id),
+    -- The customer's own PO or order number.
+    reference_number TEXT,
+    equipment_type TEXT NOT NULL,
+    commodity_description TEXT,
+    total_weight_lbs INTEGER CHECK (total_weight_lbs > 0),
+    total_pieces INTEGER CHECK (total_pieces > 0),
+    origin_name TEXT,
+    origin_address TEXT,
+    origin_city TEXT NOT NULL,
+    origin_state TEXT NOT NULL,
+    origin_postal_code TEXT,
+    destination_name TEXT,
+    destination_address TEXT,
+    destination_city TEXT NOT NULL,
+    destination_state TEXT NOT NULL,
+    destination_postal_code TEXT,
+    pickup_date DATE NOT NULL,
+    delivery_date DATE NOT NULL,
+    notes TEXT,
+    status TEXT NOT NULL DEFAULT 'submitted' CHECK (status IN ('submitted', 'accepted', 'declined')),
+    -- The load booked from an accepted request.
+    load_id UUID REFERENCES loads(id),
+    decided_by UUID REFERENCES users(id),
+    decided_at TIMESTAMPTZ,
+    decline_reason TEXT,
+    requested_by UUID NOT NULL REFERENCES users(id),
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    CHECK (delivery_date >= pickup_date)
+);
+
+CREATE INDEX idx_shipment_requests_customer ON shipment_requests(customer_id, created_at);
+CREATE INDEX idx_shipment_requests_pending ON shipment_requests(company_id, created_at) WHERE status = 'submitted';
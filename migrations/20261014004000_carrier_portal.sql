@@ -0,0 +1,28 @@
+-- Carrier portal: user accounts that sign in for one of the company's
+-- contracted carriers, and the load tenders offered to carriers for them
+-- to accept or decline.
+
+-- Set on carrier portal accounts: the carrier they act for.
+ALTER TABLE users ADD COLUMN carrier_id UUID REFERENCES carriers(id);
+
+CREATE INDEX idx_users_carrier ON users(carrier_id) WHERE carrier_id IS NOT NULL;
+
+CREATE TABLE carrier_tenders (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    company_id UUID NOT NULL REFERENCES companies(id),
+    load_id UUID NOT NULL REFERENCES loads(id) ON DELETE CASCADE,
+    carrier_id UUID NOT NULL REFERENCES carriers(id),
+    carrier_rate NUMERIC(12, 2) NOT NULL CHECK (carrier_rate > 0),
+    status TEXT NOT NULL DEFAULT 'offered' CHECK (status IN ('offered', 'accepted', 'declined', 'withdrawn')),
+    -- An offer still open past this can no longer be accepted.
+    expires_at TIMESTAMPTZ,
+    decline_reason TEXT,
+    offered_by UUID NOT NULL REFERENCES users(id),
+    responded_by UUID REFERENCES users(id),
+    responded_at TIMESTAMPTZ,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+-- One open offer per carrier per load.
+CREATE UNIQUE INDEX idx_carrier_tenders_open ON carrier_tenders(load_id, carrier_id) WHERE status = 'offered';
+CREATE INDEX idx_carrier_tenders_carrier ON carrier_tenders(carrier_id, created_at);
@@ -0,0 +1,49 @@
+-- Spot quotes: a rate offered to a customer for one lane and date, tracked
+-- until the customer accepts it, it lapses or it's lost, and converted
+-- into a load once accepted.
+
+CREATE TABLE quotes (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    company_id UUID NOT NULL REFERENCES companies(id),
+    quote_number TEXT NOT NULL,
+    customer_id UUID NOT NULL REFERENCES customers(id),
+    -- The customer's own RFQ or order number.
+    reference_number TEXT,
+    equipment_type TEXT NOT NULL,
+    commodity_description TEXT,
+    total_weight_lbs INTEGER CHECK (total_weight_lbs > 0),
+    origin_name TEXT,
+    origin_address TEXT,
+    origin_city TEXT NOT NULL,
+    origin_state TEXT NOT NULL,
+    origin_postal_code TEXT,
+    destination_name TEXT,
+    destination_address TEXT,
+    destination_city TEXT NOT NULL,
+    destination_state TEXT NOT NULL,
+    destination_postal_code TEXT,
+    pickup_date DATE NOT NULL,
+    delivery_date DATE NOT NULL,
+    -- All-in rate offered to the customer.
+    rate NUMERIC(12, 2) NOT NULL CHECK (rate > 0),
+    -- A sent quote still unanswered after this day expires.
+    valid_until DATE NOT NULL,
+    notes TEXT,
+    status TEXT NOT NULL DEFAULT 'sent' CHECK (status IN ('sent', 'accepted', 'expired', 'lost')),
+    lost_reason TEXT,
+    -- When it was accepted, lost or expired.
+    closed_at TIMESTAMPTZ,
+    -- The load an accepted quote was converted into.
+    load_id UUID REFERENCES loads(id),
+    converted_by UUID REFERENCES users(id),
+    converted_at TIMESTAMPTZ,
+    created_by UUID NOT NULL REFERENCES users(id),
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    UNIQUE (company_id, quote_number),
+    CHECK (delivery_date >= pickup_date)
+);
+
+CREATE INDEX idx_quotes_company ON quotes(company_id, status, created_at);
+CREATE INDEX idx_quotes_customer ON quotes(customer_id, created_at);
+CREATE INDEX idx_quotes_open ON quotes(valid_until) WHERE status = 'sent';
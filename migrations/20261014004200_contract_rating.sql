@@ -0,0 +1,86 @@
+-- Customer contracts and tariff rating: each contract's lane rates and
+-- minimums, its fuel surcharge table and accessorial schedule, and the
+-- weekly diesel price the fuel surcharge is looked up by.
+
+CREATE TABLE customer_contracts (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    company_id UUID NOT NULL REFERENCES companies(id),
+    customer_id UUID NOT NULL REFERENCES customers(id),
+    contract_number TEXT NOT NULL,
+    effective_on DATE NOT NULL,
+    -- Open-ended when unset.
+    expires_on DATE,
+    -- How the fuel surcharge table's amounts apply: dollars per mile, a
+    -- percentage of linehaul, or no fuel surcharge at all.
+    fuel_surcharge_method TEXT NOT NULL DEFAULT 'none'
+        CHECK (fuel_surcharge_method IN ('none', 'per_mile', 'percent')),
+    active BOOLEAN NOT NULL DEFAULT TRUE,
+    notes TEXT,
+    created_by UUID NOT NULL REFERENCES users(id),
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    UNIQUE (company_id, contract_number),
+    CHECK (expires_on IS NULL OR expires_on >= effective_on)
+);
+
+CREATE INDEX idx_customer_contracts_customer ON customer_contracts(customer_id, effective_on) WHERE active;
+
+-- A city left unset matches anywhere in the state, and an unset equipment
+-- type matches any equipment; the most specific matching lane wins.
+CREATE TABLE contract_lanes (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    company_id UUID NOT NULL REFERENCES companies(id),
+    contract_id UUID NOT NULL REFERENCES customer_contracts(id) ON DELETE CASCADE,
+    origin_city TEXT,
+    origin_state TEXT NOT NULL,
+    destination_city TEXT,
+    destination_state TEXT NOT NULL,
+    equipment_type TEXT,
+    rate_type TEXT NOT NULL CHECK (rate_type IN ('flat', 'per_mile')),
+    rate NUMERIC(12, 2) NOT NULL CHECK (rate > 0),
+    -- Linehaul is never billed below this.
+    minimum_charge NUMERIC(12, 2) CHECK (minimum_charge > 0),
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_contract_lanes_contract ON contract_lanes(contract_id, origin_state, destination_state);
+
+-- One row per diesel price band; `price_to` is exclusive, and unset on the
+-- top band.
+CREATE TABLE contract_fuel_surcharges (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    contract_id UUID NOT NULL REFERENCES customer_contracts(id) ON DELETE CASCADE,
+    price_from NUMERIC(6, 3) NOT NULL CHECK (price_from >= 0),
+    price_to NUMERIC(6, 3),
+    amount NUMERIC(8, 3) NOT NULL CHECK (amount >= 0),
+    UNIQUE (contract_id, price_from),
+    CHECK (price_to IS NULL OR price_to > price_from)
+);
+
+CREATE TABLE contract_accessorials (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    contract_id UUID NOT NULL REFERENCES customer_contracts(id) ON DELETE CASCADE,
+    charge_type TEXT NOT NULL,
+    description TEXT,
+    amount NUMERIC(12, 2) NOT NULL CHECK (amount >= 0),
+    -- Added to every load the contract rates, not only when the charge
+    -- is incurred.
+    auto_apply BOOLEAN NOT NULL DEFAULT FALSE,
+    UNIQUE (contract_id, charge_type)
+);
+
+-- The weekly on-highway diesel average fuel surcharges are pegged to.
+CREATE TABLE diesel_prices (
+    company_id UUID NOT NULL REFERENCES companies(id),
+    effective_on DATE NOT NULL,
+    price_per_gallon NUMERIC(6, 3) NOT NULL CHECK (price_per_gallon > 0),
+    recorded_by UUID NOT NULL REFERENCES users(id),
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (company_id, effective_on)
+);
+
+-- The contract a load was rated from. Charges the rating added carry it
+-- too, so re-rating replaces them without touching entries made by hand.
+ALTER TABLE loads ADD COLUMN contract_id UUID REFERENCES customer_contracts(id);
+ALTER TABLE loads ADD COLUMN rated_at TIMESTAMPTZ;
+ALTER TABLE load_accessorials ADD COLUMN contract_id UUID REFERENCES customer_contracts(id);
@@ -0,0 +1,59 @@
+-- Load templates for dedicated freight: the lane, freight and rate of a
+-- run booked over and over, optionally on a weekly schedule the recurring
+-- loads job books ahead of. Loads booked from a template stay linked to
+-- it so a change can be carried to the ones not yet dispatched.
+
+CREATE TABLE load_templates (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    company_id UUID NOT NULL REFERENCES companies(id),
+    customer_id UUID NOT NULL REFERENCES customers(id),
+    name TEXT NOT NULL,
+    -- Loads are numbered <prefix>-<pickup date as YYYYMMDD>.
+    load_number_prefix TEXT NOT NULL,
+    reference_number TEXT,
+    load_type TEXT NOT NULL,
+    mode TEXT NOT NULL DEFAULT 'truckload',
+    equipment_type TEXT NOT NULL,
+    commodity_description TEXT,
+    total_weight_lbs INTEGER CHECK (total_weight_lbs > 0),
+    origin_name TEXT,
+    origin_address TEXT,
+    origin_city TEXT NOT NULL,
+    origin_state TEXT NOT NULL,
+    origin_postal_code TEXT,
+    destination_name TEXT,
+    destination_address TEXT,
+    destination_city TEXT NOT NULL,
+    destination_state TEXT NOT NULL,
+    destination_postal_code TEXT,
+    -- Days from pickup to delivery.
+    transit_days INTEGER NOT NULL DEFAULT 1 CHECK (transit_days >= 0),
+    -- Unset to rate booked loads from the customer's contract.
+    customer_rate NUMERIC(12, 2) CHECK (customer_rate > 0),
+    carrier_rate NUMERIC(12, 2) CHECK (carrier_rate > 0),
+    -- ISO weekdays (1 = Monday) the run picks up on; empty for a template
+    -- only booked by hand.
+    weekdays SMALLINT[] NOT NULL DEFAULT '{}',
+    starts_on DATE NOT NULL,
+    ends_on DATE,
+    -- How many days ahead of pickup the job books a load.
+    horizon_days INTEGER NOT NULL DEFAULT 14 CHECK (horizon_days BETWEEN 1 AND 90),
+    -- The last pickup date the job has booked up to.
+    generated_through DATE,
+    active BOOLEAN NOT NULL DEFAULT TRUE,
+    created_by UUID NOT NULL REFERENCES users(id),
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    UNIQUE (company_id, load_number_prefix),
+    CHECK (weekdays <@ ARRAY[1, 2, 3, 4, 5, 6, 7]::SMALLINT[]),
+    CHECK (ends_on IS NULL OR ends_on >= starts_on)
+);
+
+CREATE INDEX idx_load_templates_customer ON load_templates(customer_id);
+CREATE INDEX idx_load_templates_recurring ON load_templates(generated_through NULLS FIRST)
+    WHERE active AND weekdays <> '{}';
+
+ALTER TABLE loads ADD COLUMN template_id UUID REFERENCES load_templates(id);
+
+-- A template books one load per pickup date.
+CREATE UNIQUE INDEX idx_loads_template_pickup ON loads(template_id, pickup_date) WHERE template_id IS NOT NULL;
@@ -0,0 +1,5 @@
+-- Loads split off another load's freight to run on a second truck. The
+-- split keeps its own rate and is billed on its own.
+ALTER TABLE loads ADD COLUMN split_from_load_id UUID REFERENCES loads(id);
+
+CREATE INDEX idx_loads_split_from ON loads(split_from_load_id) WHERE split_from_load_id IS NOT NULL;
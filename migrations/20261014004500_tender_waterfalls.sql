@@ -0,0 +1,50 @@
+-- Carrier tendering: counter-offers, offers that lapse unanswered, and
+-- waterfalls that offer a load down a ranked list of carriers, one at a
+-- time, until one of them takes it.
+
+ALTER TABLE carrier_tenders DROP CONSTRAINT carrier_tenders_status_check;
+ALTER TABLE carrier_tenders ADD CONSTRAINT carrier_tenders_status_check
+    CHECK (status IN ('offered', 'countered', 'accepted', 'declined', 'withdrawn', 'expired'));
+
+-- The carrier's counter; the offer waits on dispatch to take it or
+-- withdraw.
+ALTER TABLE carrier_tenders ADD COLUMN counter_rate NUMERIC(12, 2) CHECK (counter_rate > 0);
+ALTER TABLE carrier_tenders ADD COLUMN counter_note TEXT;
+ALTER TABLE carrier_tenders ADD COLUMN countered_at TIMESTAMPTZ;
+
+DROP INDEX idx_carrier_tenders_open;
+CREATE UNIQUE INDEX idx_carrier_tenders_open ON carrier_tenders(load_id, carrier_id) WHERE status IN ('offered', 'countered');
+CREATE INDEX idx_carrier_tenders_expiring ON carrier_tenders(expires_at) WHERE status = 'offered' AND expires_at IS NOT NULL;
+
+CREATE TABLE tender_waterfalls (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    company_id UUID NOT NULL REFERENCES companies(id),
+    load_id UUID NOT NULL REFERENCES loads(id) ON DELETE CASCADE,
+    status TEXT NOT NULL DEFAULT 'running' CHECK (status IN ('running', 'covered', 'exhausted', 'cancelled')),
+    -- How long each carrier has to answer before the load rolls to the
+    -- next.
+    response_minutes INTEGER NOT NULL CHECK (response_minutes > 0),
+    created_by UUID NOT NULL REFERENCES users(id),
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    finished_at TIMESTAMPTZ
+);
+
+-- One running waterfall per load.
+CREATE UNIQUE INDEX idx_tender_waterfalls_running ON tender_waterfalls(load_id) WHERE status = 'running';
+
+CREATE TABLE tender_waterfall_steps (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    waterfall_id UUID NOT NULL REFERENCES tender_waterfalls(id) ON DELETE CASCADE,
+    position INTEGER NOT NULL,
+    carrier_id UUID NOT NULL REFERENCES carriers(id),
+    carrier_rate NUMERIC(12, 2) NOT NULL CHECK (carrier_rate > 0),
+    status TEXT NOT NULL DEFAULT 'pending' CHECK (status IN ('pending', 'tendered', 'skipped')),
+    tender_id UUID REFERENCES carrier_tenders(id),
+    -- Why the carrier couldn't be offered the load when its turn came.
+    skip_reason TEXT,
+    UNIQUE (waterfall_id, position),
+    UNIQUE (waterfall_id, carrier_id)
+);
+
+CREATE INDEX idx_tender_waterfall_steps_tender ON tender_waterfall_steps(tender_id) WHERE tender_id IS NOT NULL;
@@ -0,0 +1,53 @@
+-- Dock appointments: a facility's receiving hours and dock doors, the
+-- appointments booked against them for load stops, and a flag on
+-- appointments the truck can't make given the drive from the stop before.
+
+-- Hours are kept in the facility's local time. Facilities created before
+-- this read theirs in UTC until a time zone is set.
+ALTER TABLE customer_facilities ADD COLUMN timezone TEXT NOT NULL DEFAULT 'UTC';
+-- Appointments that can run at once.
+ALTER TABLE customer_facilities ADD COLUMN dock_doors INTEGER NOT NULL DEFAULT 1 CHECK (dock_doors > 0);
+ALTER TABLE customer_facilities ADD COLUMN appointment_minutes INTEGER NOT NULL DEFAULT 60
+    CHECK (appointment_minutes BETWEEN 15 AND 480);
+
+-- One row per ISO weekday the docks are open; a missing day is closed.
+CREATE TABLE facility_hours (
+    facility_id UUID NOT NULL REFERENCES customer_facilities(id) ON DELETE CASCADE,
+    weekday SMALLINT NOT NULL CHECK (weekday BETWEEN 1 AND 7),
+    opens_at TIME NOT NULL,
+    closes_at TIME NOT NULL,
+    PRIMARY KEY (facility_id, weekday),
+    CHECK (closes_at > opens_at)
+);
+
+ALTER TABLE load_stops ADD COLUMN facility_id UUID REFERENCES customer_facilities(id);
+
+CREATE TABLE dock_appointments (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    company_id UUID NOT NULL REFERENCES companies(id),
+    facility_id UUID NOT NULL REFERENCES customer_facilities(id),
+    load_id UUID NOT NULL REFERENCES loads(id) ON DELETE CASCADE,
+    stop_id UUID NOT NULL REFERENCES load_stops(id) ON DELETE CASCADE,
+    status TEXT NOT NULL DEFAULT 'requested' CHECK (status IN ('requested', 'confirmed', 'cancelled')),
+    starts_at TIMESTAMPTZ NOT NULL,
+    ends_at TIMESTAMPTZ NOT NULL,
+    -- The facility's reference for the booking.
+    confirmation_number TEXT,
+    -- Why the truck can't make it, from the last reachability check.
+    unreachable_reason TEXT,
+    checked_at TIMESTAMPTZ,
+    requested_by UUID NOT NULL REFERENCES users(id),
+    confirmed_by UUID REFERENCES users(id),
+    confirmed_at TIMESTAMPTZ,
+    cancelled_at TIMESTAMPTZ,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    CHECK (ends_at > starts_at)
+);
+
+-- A stop holds one appointment at a time; cancelling frees it to rebook.
+CREATE UNIQUE INDEX idx_dock_appointments_stop ON dock_appointments(stop_id) WHERE status <> 'cancelled';
+CREATE INDEX idx_dock_appointments_facility ON dock_appointments(facility_id, starts_at) WHERE status <> 'cancelled';
+CREATE INDEX idx_dock_appointments_load ON dock_appointments(load_id);
+CREATE INDEX idx_dock_appointments_unreachable ON dock_appointments(company_id)
+    WHERE unreachable_reason IS NOT NULL AND status <> 'cancelled';
@@ -0,0 +1,35 @@
+-- Facility master data: shippers and receivers kept once and referenced
+-- from stops, whether or not they belong to a customer, with their
+-- contacts, handling notes and a geocoded position.
+
+ALTER TABLE customer_facilities ALTER COLUMN customer_id DROP NOT NULL;
+ALTER TABLE customer_facilities ADD COLUMN facility_type TEXT NOT NULL DEFAULT 'both'
+    CHECK (facility_type IN ('shipper', 'receiver', 'both'));
+ALTER TABLE customer_facilities ADD COLUMN notes TEXT;
+ALTER TABLE customer_facilities ADD COLUMN lumper_required BOOLEAN NOT NULL DEFAULT false;
+ALTER TABLE customer_facilities ADD COLUMN appointment_required BOOLEAN NOT NULL DEFAULT false;
+-- Where the position came from: typed in, or looked up from the address.
+ALTER TABLE customer_facilities ADD COLUMN geocode_source TEXT CHECK (geocode_source IN ('manual', 'provider'));
+ALTER TABLE customer_facilities ADD COLUMN geocoded_at TIMESTAMPTZ;
+-- Why the last lookup found nothing; cleared once a position is set.
+ALTER TABLE customer_facilities ADD COLUMN geocode_error TEXT;
+
+UPDATE customer_facilities SET geocode_source = 'manual' WHERE latitude IS NOT NULL;
+
+-- Facilities outside any customer are unique by name within the company.
+CREATE UNIQUE INDEX idx_customer_facilities_unowned_name ON customer_facilities(company_id, name)
+    WHERE customer_id IS NULL;
+
+CREATE TABLE facility_contacts (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    facility_id UUID NOT NULL REFERENCES customer_facilities(id) ON DELETE CASCADE,
+    name TEXT NOT NULL,
+    -- Receiving, shipping, security desk and the like.
+    role TEXT,
+    phone TEXT,
+    email TEXT,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    CHECK (phone IS NOT NULL OR email IS NOT NULL)
+);
+
+CREATE INDEX idx_facility_contacts_facility ON facility_contacts(facility_id);
@@ -0,0 +1,87 @@
+-- Time zones for scheduling: each facility and stop carries the zone it
+-- sits in, windows and appointments are also kept as local wall time,
+-- and a load's delivery date is due at midnight where it delivers rather
+-- than midnight UTC.
+
+-- The zone most of a state or province keeps, for places without a
+-- position to look the zone up from. States split across zones get the
+-- one most of their freight sees.
+CREATE TABLE state_time_zones (
+    state TEXT PRIMARY KEY,
+    timezone TEXT NOT NULL
+);
+
+INSERT INTO state_time_zones (state, timezone) VALUES
+    ('AL', 'America/Chicago'), ('AK', 'America/Anchorage'), ('AZ', 'America/Phoenix'),
+    ('AR', 'America/Chicago'), ('CA', 'America/Los_Angeles'), ('CO', 'America/Denver'),
+    ('CT', 'America/New_York'), ('DE', 'America/New_York'), ('DC', 'America/New_York'),
+    ('FL', 'America/New_York'), ('GA', 'America/New_York'), ('HI', 'Pacific/Honolulu'),
+    ('ID', 'America/Boise'), ('IL', 'America/Chicago'), ('IN', 'America/Indiana/Indianapolis'),
+    ('IA', 'America/Chicago'), ('KS', 'America/Chicago'), ('KY', 'America/New_York'),
+    ('LA', 'America/Chicago'), ('ME', 'America/New_York'), ('MD', 'America/New_York'),
+    ('MA', 'America/New_York'), ('MI', 'America/Detroit'), ('MN', 'America/Chicago'),
+    ('MS', 'America/Chicago'), ('MO', 'America/Chicago'), ('MT', 'America/Denver'),
+    ('NE', 'America/Chicago'), ('NV', 'America/Los_Angeles'), ('NH', 'America/New_York'),
+    ('NJ', 'America/New_York'), ('NM', 'America/Denver'), ('NY', 'America/New_York'),
+    ('NC', 'America/New_York'), ('ND', 'America/Chicago'), ('OH', 'America/New_York'),
+    ('OK', 'America/Chicago'), ('OR', 'America/Los_Angeles'), ('PA', 'America/New_York'),
+    ('RI', 'America/New_York'), ('SC', 'America/New_York'), ('SD', 'America/Chicago'),
+    ('TN', 'America/Chicago'), ('TX', 'America/Chicago'), ('UT', 'America/Denver'),
+    ('VT', 'America/New_York'), ('VA', 'America/New_York'), ('WA', 'America/Los_Angeles'),
+    ('WV', 'America/New_York'), ('WI', 'America/Chicago'), ('WY', 'America/Denver'),
+    ('PR', 'America/Puerto_Rico'),
+    ('AB', 'America/Edmonton'), ('BC', 'America/Vancouver'), ('MB', 'America/Winnipeg'),
+    ('NB', 'America/Moncton'), ('NL', 'America/St_Johns'), ('NS', 'America/Halifax'),
+    ('NT', 'America/Yellowknife'), ('NU', 'America/Iqaluit'), ('ON', 'America/Toronto'),
+    ('PE', 'America/Halifax'), ('QC', 'America/Toronto'), ('SK', 'America/Regina'),
+    ('YT', 'America/Whitehorse');
+
+-- A facility's zone is now derived from its position when it's saved.
+-- The UTC placeholder is dropped where no hours were set in it.
+ALTER TABLE customer_facilities ALTER COLUMN timezone DROP DEFAULT;
+ALTER TABLE customer_facilities ALTER COLUMN timezone DROP NOT NULL;
+UPDATE customer_facilities f SET timezone = z.timezone
+FROM state_time_zones z
+WHERE z.state = UPPER(f.state) AND f.timezone = 'UTC'
+AND NOT EXISTS (SELECT 1 FROM facility_hours h WHERE h.facility_id = f.id);
+UPDATE customer_facilities f SET timezone = NULL
+WHERE f.timezone = 'UTC' AND NOT EXISTS (SELECT 1 FROM facility_hours h WHERE h.facility_id = f.id);
+
+-- Windows stay instants; the local columns are what the facility's clock
+-- reads, unset while the stop's zone is unknown.
+ALTER TABLE load_stops ADD COLUMN timezone TEXT;
+UPDATE load_stops s SET timezone = COALESCE(
+    (SELECT f.timezone FROM customer_facilities f WHERE f.id = s.facility_id),
+    (SELECT z.timezone FROM state_time_zones z WHERE z.state = UPPER(s.state))
+);
+ALTER TABLE load_stops ADD COLUMN window_start_local TIMESTAMP
+    GENERATED ALWAYS AS (window_start AT TIME ZONE timezone) STORED;
+ALTER TABLE load_stops ADD COLUMN window_end_local TIMESTAMP
+    GENERATED ALWAYS AS (window_end AT TIME ZONE timezone) STORED;
+
+-- The zone the appointment was booked in, kept with it so a later change
+-- to the facility doesn't move what the facility agreed to.
+ALTER TABLE dock_appointments ADD COLUMN timezone TEXT;
+UPDATE dock_appointments a SET timezone = COALESCE(f.timezone, 'UTC')
+FROM customer_facilities f WHERE f.id = a.facility_id;
+ALTER TABLE dock_appointments ALTER COLUMN timezone SET NOT NULL;
+ALTER TABLE dock_appointments ADD COLUMN starts_at_local TIMESTAMP
+    GENERATED ALWAYS AS (starts_at AT TIME ZONE timezone) STORED;
+ALTER TABLE dock_appointments ADD COLUMN ends_at_local TIMESTAMP
+    GENERATED ALWAYS AS (ends_at AT TIME ZONE timezone) STORED;
+
+-- The zone of the load's final delivery stop; the delivery date runs to
+-- midnight there, or midnight UTC while it's unknown.
+ALTER TABLE loads ADD COLUMN delivery_timezone TEXT;
+UPDATE loads l SET delivery_timezone = (
+    SELECT s.timezone FROM load_stops s
+    WHERE s.load_id = l.id AND s.stop_type = 'delivery' AND s.timezone IS NOT NULL
+    ORDER BY s.stop_sequence DESC
+    LIMIT 1
+);
+ALTER TABLE loads ADD COLUMN delivery_due_at TIMESTAMPTZ
+    GENERATED ALWAYS AS ((delivery_date + 1)::timestamp AT TIME ZONE COALESCE(delivery_timezone, 'UTC')) STORED;
+
+ALTER TABLE dispatch_board_entries ADD COLUMN delivery_due_at TIMESTAMPTZ;
+ALTER TABLE dispatch_board_entries ADD COLUMN next_stop_timezone TEXT;
+UPDATE dispatch_board_entries b SET delivery_due_at = l.delivery_due_at FROM loads l WHERE l.id = b.load_id;
@@ -0,0 +1,52 @@
+-- Driver availability: home-time requests, time off entered by the
+-- office, and recurring days off. All of it lands on the driver calendar,
+-- which dispatch and the recommendation engine check before offering a
+-- driver a load.
+
+CREATE TABLE home_time_requests (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    company_id UUID NOT NULL REFERENCES companies(id),
+    driver_id UUID NOT NULL REFERENCES drivers(id),
+    starts_on DATE NOT NULL,
+    ends_on DATE NOT NULL,
+    reason TEXT,
+    status TEXT NOT NULL DEFAULT 'pending' CHECK (status IN ('pending', 'approved', 'denied', 'cancelled')),
+    requested_by UUID NOT NULL REFERENCES users(id),
+    decided_by UUID REFERENCES users(id),
+    decided_at TIMESTAMPTZ,
+    -- Why it was denied, or anything dispatch wants the driver to know.
+    decision_note TEXT,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    CHECK (ends_on >= starts_on)
+);
+
+CREATE INDEX idx_home_time_requests_driver ON home_time_requests(driver_id, starts_on);
+CREATE INDEX idx_home_time_requests_pending ON home_time_requests(company_id) WHERE status = 'pending';
+
+-- A driver's standing schedule: the same weekdays off every week, or a
+-- rotation of days on then days off counted from the first working day.
+-- Its days off are written to the calendar ahead of time and rolled
+-- forward as time passes.
+CREATE TABLE driver_availability_patterns (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    company_id UUID NOT NULL REFERENCES companies(id),
+    driver_id UUID NOT NULL UNIQUE REFERENCES drivers(id),
+    pattern_type TEXT NOT NULL CHECK (pattern_type IN ('weekly', 'rotation')),
+    -- ISO weekdays, 1 (Monday) to 7 (Sunday).
+    off_weekdays SMALLINT[] NOT NULL DEFAULT '{}',
+    days_on INTEGER CHECK (days_on > 0),
+    days_off INTEGER CHECK (days_off > 0),
+    anchor_date DATE,
+    starts_on DATE NOT NULL,
+    ends_on DATE,
+    -- Days off are on the calendar through this date.
+    expanded_through DATE,
+    created_by UUID NOT NULL REFERENCES users(id),
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    CHECK (pattern_type <> 'weekly' OR cardinality(off_weekdays) > 0),
+    CHECK (pattern_type <> 'rotation' OR (days_on IS NOT NULL AND days_off IS NOT NULL AND anchor_date IS NOT NULL)),
+    CHECK (ends_on IS NULL OR ends_on >= starts_on)
+);
+
+CREATE INDEX idx_driver_calendar_source ON driver_calendar_events(source_id);
@@ -0,0 +1,67 @@
+-- Driver recruiting: applicants kept apart from drivers while they move
+-- through screening, the road test and an offer. Hiring one creates the
+-- driver record; the application, its stage history and its documents
+-- stay behind as the record of how they were hired.
+
+CREATE TABLE driver_applicants (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    company_id UUID NOT NULL REFERENCES companies(id),
+    first_name TEXT NOT NULL,
+    last_name TEXT NOT NULL,
+    email TEXT,
+    phone TEXT NOT NULL,
+    cdl_number TEXT NOT NULL,
+    cdl_state TEXT NOT NULL,
+    cdl_class TEXT NOT NULL,
+    cdl_expiry DATE NOT NULL,
+    cdl_endorsements TEXT[] NOT NULL DEFAULT '{}',
+    hazmat_endorsement_expiry DATE,
+    years_experience INTEGER CHECK (years_experience >= 0),
+    -- Where the applicant heard about the job: a referral, a job board.
+    source TEXT,
+    stage TEXT NOT NULL DEFAULT 'applied'
+        CHECK (stage IN ('applied', 'mvr_psp_ordered', 'road_test', 'offer', 'hired', 'rejected', 'withdrawn')),
+    stage_changed_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    mvr_ordered_at TIMESTAMPTZ,
+    psp_ordered_at TIMESTAMPTZ,
+    road_test_on DATE,
+    road_test_passed BOOLEAN,
+    road_test_notes TEXT,
+    -- The terms offered, carried onto the driver record at hire.
+    offer_pay_type TEXT,
+    offer_pay_rate NUMERIC(12, 4),
+    offer_start_date DATE,
+    -- Why the application was rejected or withdrawn.
+    closed_reason TEXT,
+    driver_id UUID UNIQUE REFERENCES drivers(id),
+    hired_at TIMESTAMPTZ,
+    created_by UUID NOT NULL REFERENCES users(id),
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    CHECK ((stage = 'hired') = (driver_id IS NOT NULL))
+);
+
+CREATE INDEX idx_driver_applicants_company ON driver_applicants(company_id, stage, created_at);
+-- One open application per license.
+CREATE UNIQUE INDEX idx_driver_applicants_open_cdl ON driver_applicants(company_id, cdl_state, cdl_number)
+    WHERE stage NOT IN ('hired', 'rejected', 'withdrawn');
+
+CREATE TABLE applicant_stage_history (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    applicant_id UUID NOT NULL REFERENCES driver_applicants(id) ON DELETE CASCADE,
+    from_stage TEXT,
+    to_stage TEXT NOT NULL,
+    note TEXT,
+    changed_by UUID NOT NULL REFERENCES users(id),
+    changed_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_applicant_stage_history_applicant ON applicant_stage_history(applicant_id, changed_at);
+
+-- The license copy, medical card, MVR and PSP reports and the like. The
+-- files are ordinary documents, moved onto the driver at hire.
+CREATE TABLE applicant_documents (
+    applicant_id UUID NOT NULL REFERENCES driver_applicants(id) ON DELETE CASCADE,
+    document_id UUID NOT NULL REFERENCES documents(id) ON DELETE CASCADE,
+    PRIMARY KEY (applicant_id, document_id)
+);
@@ -0,0 +1,85 @@
+-- Drug and alcohol testing under 49 CFR Part 382: each driver's tests,
+-- the quarterly random selections drawn from the testing pool, and the
+-- FMCSA Clearinghouse queries run on them. Together they give each driver
+-- a testing status that dispatch checks before assigning a load.
+
+CREATE TABLE random_test_selections (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    company_id UUID NOT NULL REFERENCES companies(id),
+    year INTEGER NOT NULL,
+    quarter INTEGER NOT NULL CHECK (quarter BETWEEN 1 AND 4),
+    -- Annual rates the quarter's share was drawn at, and the pool drawn from.
+    drug_rate_percent NUMERIC(5, 2) NOT NULL,
+    alcohol_rate_percent NUMERIC(5, 2) NOT NULL,
+    pool_size INTEGER NOT NULL,
+    -- NULL when drawn by the quarterly job.
+    generated_by UUID REFERENCES users(id),
+    generated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    UNIQUE (company_id, year, quarter)
+);
+
+CREATE TABLE drug_alcohol_tests (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    company_id UUID NOT NULL REFERENCES companies(id),
+    driver_id UUID NOT NULL REFERENCES drivers(id),
+    test_type TEXT NOT NULL CHECK (test_type IN (
+        'pre_employment', 'random', 'post_accident', 'reasonable_suspicion', 'return_to_duty', 'follow_up'
+    )),
+    substance TEXT NOT NULL CHECK (substance IN ('drug', 'alcohol')),
+    -- The random selection or accident that called for the test.
+    selection_id UUID REFERENCES random_test_selections(id),
+    incident_id UUID REFERENCES incidents(id),
+    collected_on DATE NOT NULL,
+    -- Custody and control form number, or the breath test's sequence.
+    specimen_id TEXT,
+    collection_site TEXT,
+    result TEXT NOT NULL DEFAULT 'pending'
+        CHECK (result IN ('pending', 'negative', 'positive', 'refusal', 'cancelled')),
+    result_on DATE,
+    notes TEXT,
+    recorded_by UUID NOT NULL REFERENCES users(id),
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    CHECK (test_type <> 'random' OR selection_id IS NOT NULL),
+    CHECK (test_type <> 'post_accident' OR incident_id IS NOT NULL),
+    CHECK ((result = 'pending') = (result_on IS NULL))
+);
+
+CREATE INDEX idx_drug_alcohol_tests_driver ON drug_alcohol_tests(driver_id, collected_on);
+CREATE INDEX idx_drug_alcohol_tests_company ON drug_alcohol_tests(company_id, collected_on);
+
+-- Drivers drawn for a quarter, once per substance. The test taken in
+-- answer is linked once recorded.
+CREATE TABLE random_test_selected_drivers (
+    selection_id UUID NOT NULL REFERENCES random_test_selections(id) ON DELETE CASCADE,
+    driver_id UUID NOT NULL REFERENCES drivers(id),
+    substance TEXT NOT NULL CHECK (substance IN ('drug', 'alcohol')),
+    test_id UUID REFERENCES drug_alcohol_tests(id),
+    PRIMARY KEY (selection_id, driver_id, substance)
+);
+
+CREATE TABLE clearinghouse_queries (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    company_id UUID NOT NULL REFERENCES companies(id),
+    driver_id UUID NOT NULL REFERENCES drivers(id),
+    -- Full queries need the driver's consent in the Clearinghouse and are
+    -- required before hire; limited queries cover the annual re-query.
+    query_type TEXT NOT NULL CHECK (query_type IN ('full', 'limited')),
+    queried_on DATE NOT NULL,
+    result TEXT NOT NULL CHECK (result IN ('no_violations', 'violations_found')),
+    notes TEXT,
+    recorded_by UUID NOT NULL REFERENCES users(id),
+    -- When the safety team was reminded the annual re-query is due.
+    requery_reminded_at TIMESTAMPTZ,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_clearinghouse_queries_driver ON clearinghouse_queries(driver_id, queried_on);
+
+-- Where the driver stands, kept current as records change and as time
+-- passes: compliant, due (a re-query or random test coming up),
+-- incomplete (pre-employment records missing) or prohibited from
+-- safety-sensitive work.
+ALTER TABLE drivers ADD COLUMN testing_status TEXT NOT NULL DEFAULT 'incomplete'
+    CHECK (testing_status IN ('compliant', 'due', 'incomplete', 'prohibited'));
+ALTER TABLE drivers ADD COLUMN testing_status_reason TEXT;
@@ -0,0 +1,24 @@
+-- Safety reporting on incidents: roadside and moving violations alongside
+-- accidents, how severe each was, and whether an accident is recordable
+-- on the DOT accident register (49 CFR 390.15): a fatality, an injury
+-- treated away from the scene, or a vehicle towed away. Preventability is
+-- decided on review, so it stays NULL until then.
+
+ALTER TABLE incidents DROP CONSTRAINT incidents_incident_type_check;
+ALTER TABLE incidents ADD CONSTRAINT incidents_incident_type_check
+    CHECK (incident_type IN ('accident', 'violation', 'cargo_damage', 'injury', 'theft', 'other'));
+
+ALTER TABLE incidents ADD COLUMN severity TEXT NOT NULL DEFAULT 'minor'
+    CHECK (severity IN ('minor', 'moderate', 'major', 'fatal'));
+ALTER TABLE incidents ADD COLUMN fatalities INTEGER NOT NULL DEFAULT 0 CHECK (fatalities >= 0);
+ALTER TABLE incidents ADD COLUMN injuries INTEGER NOT NULL DEFAULT 0 CHECK (injuries >= 0);
+ALTER TABLE incidents ADD COLUMN tow_away BOOLEAN NOT NULL DEFAULT FALSE;
+ALTER TABLE incidents ADD COLUMN dot_recordable BOOLEAN GENERATED ALWAYS AS (
+    incident_type = 'accident' AND (fatalities > 0 OR injuries > 0 OR tow_away)
+) STORED;
+ALTER TABLE incidents ADD COLUMN preventable BOOLEAN;
+-- The regulation cited for a violation, e.g. 392.2-SLLS4.
+ALTER TABLE incidents ADD COLUMN violation_code TEXT;
+ALTER TABLE incidents ADD COLUMN out_of_service BOOLEAN NOT NULL DEFAULT FALSE;
+
+CREATE INDEX idx_incidents_recordable ON incidents(company_id, occurred_at) WHERE dot_recordable;
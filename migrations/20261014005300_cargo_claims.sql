@@ -0,0 +1,34 @@
+-- Cargo claims filed against loads by shippers, consignees or customers:
+-- who's claiming and why, the claim's progress from filing through
+-- investigation to settlement or denial, and the evidence gathered. What
+-- a settled claim costs is charged to the load, and so to the customer's
+-- profitability.
+
+ALTER TABLE claims ADD COLUMN claimant_name TEXT NOT NULL DEFAULT '';
+ALTER TABLE claims ADD COLUMN claimant_type TEXT NOT NULL DEFAULT 'customer'
+    CHECK (claimant_type IN ('customer', 'shipper', 'consignee', 'other'));
+ALTER TABLE claims ADD COLUMN cause TEXT NOT NULL DEFAULT 'other'
+    CHECK (cause IN ('damage', 'shortage', 'loss', 'theft', 'contamination', 'temperature', 'delay', 'other'));
+ALTER TABLE claims ADD COLUMN description TEXT;
+-- The claimant's own reference for the claim.
+ALTER TABLE claims ADD COLUMN claimant_reference TEXT;
+ALTER TABLE claims ADD COLUMN settled_amount NUMERIC(12, 2);
+ALTER TABLE claims ADD COLUMN resolution_note TEXT;
+ALTER TABLE claims ADD COLUMN resolved_by UUID REFERENCES users(id);
+ALTER TABLE claims ADD COLUMN resolved_at TIMESTAMPTZ;
+ALTER TABLE claims ADD COLUMN filed_by UUID REFERENCES users(id);
+ALTER TABLE claims ADD COLUMN updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW();
+ALTER TABLE claims ADD CONSTRAINT claims_status_check
+    CHECK (status IN ('filed', 'investigating', 'settled', 'denied'));
+ALTER TABLE claims ADD CONSTRAINT claims_settled_amount_check
+    CHECK ((status = 'settled') = (settled_amount IS NOT NULL));
+
+CREATE INDEX idx_claims_company ON claims(company_id, status, created_at);
+
+-- Photos, delivery receipts, inspection reports and the claimant's
+-- paperwork. The files themselves are ordinary documents.
+CREATE TABLE claim_documents (
+    claim_id UUID NOT NULL REFERENCES claims(id) ON DELETE CASCADE,
+    document_id UUID NOT NULL REFERENCES documents(id) ON DELETE CASCADE,
+    PRIMARY KEY (claim_id, document_id)
+);
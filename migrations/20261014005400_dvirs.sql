@@ -0,0 +1,50 @@
+-- Driver vehicle inspection reports (49 CFR 396.11 and 396.13): the
+-- pre-trip and post-trip inspections drivers submit from the app, each
+-- defect they note against the truck or trailer, and the mechanic's
+-- sign-off that it was repaired or needed no repair. Equipment with an
+-- out-of-service defect still open can't be dispatched.
+
+CREATE TABLE dvirs (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    company_id UUID NOT NULL REFERENCES companies(id),
+    driver_id UUID NOT NULL REFERENCES drivers(id),
+    truck_id UUID NOT NULL REFERENCES trucks(id),
+    trailer_id UUID REFERENCES trailers(id),
+    inspection_type TEXT NOT NULL CHECK (inspection_type IN ('pre_trip', 'post_trip')),
+    odometer_miles INTEGER,
+    location_description TEXT,
+    remarks TEXT,
+    -- Whether the driver found the vehicle safe to operate.
+    satisfactory BOOLEAN NOT NULL,
+    inspected_at TIMESTAMPTZ NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_dvirs_truck ON dvirs(truck_id, inspected_at);
+CREATE INDEX idx_dvirs_driver ON dvirs(driver_id, inspected_at);
+
+CREATE TABLE dvir_defects (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    company_id UUID NOT NULL REFERENCES companies(id),
+    dvir_id UUID NOT NULL REFERENCES dvirs(id) ON DELETE CASCADE,
+    -- The unit the defect is on: the truck or the trailer, not both.
+    truck_id UUID REFERENCES trucks(id),
+    trailer_id UUID REFERENCES trailers(id),
+    component TEXT NOT NULL,
+    description TEXT NOT NULL,
+    out_of_service BOOLEAN NOT NULL DEFAULT FALSE,
+    status TEXT NOT NULL DEFAULT 'open' CHECK (status IN ('open', 'repaired', 'no_repair_needed')),
+    mechanic_name TEXT,
+    sign_off_note TEXT,
+    signed_off_by UUID REFERENCES users(id),
+    signed_off_at TIMESTAMPTZ,
+    -- The repair as it was entered in the maintenance log.
+    maintenance_record_id UUID REFERENCES maintenance_records(id),
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    CHECK ((truck_id IS NULL) <> (trailer_id IS NULL)),
+    CHECK ((status = 'open') = (signed_off_at IS NULL))
+);
+
+CREATE INDEX idx_dvir_defects_dvir ON dvir_defects(dvir_id);
+CREATE INDEX idx_dvir_defects_open_truck ON dvir_defects(truck_id) WHERE status = 'open';
+CREATE INDEX idx_dvir_defects_open_trailer ON dvir_defects(trailer_id) WHERE status = 'open';
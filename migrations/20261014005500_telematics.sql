@@ -0,0 +1,62 @@
+-- Inbound ELD webhooks. Each company connects its provider account with
+-- the secret the provider signs deliveries with; GPS, engine fault and
+-- duty status events are normalized into location history, engine faults
+-- and the driver's status.
+
+CREATE TABLE telematics_integrations (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    company_id UUID NOT NULL REFERENCES companies(id),
+    provider TEXT NOT NULL CHECK (provider IN ('samsara', 'motive')),
+    webhook_secret TEXT NOT NULL,
+    active BOOLEAN NOT NULL DEFAULT TRUE,
+    last_event_at TIMESTAMPTZ,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    UNIQUE (company_id, provider)
+);
+
+-- The provider's vehicles as they've been seen. Matched to a truck by
+-- unit number or VIN when first seen, otherwise mapped by hand; events
+-- for an unmapped vehicle are dropped.
+CREATE TABLE telematics_vehicles (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    company_id UUID NOT NULL REFERENCES companies(id),
+    integration_id UUID NOT NULL REFERENCES telematics_integrations(id) ON DELETE CASCADE,
+    provider_vehicle_id TEXT NOT NULL,
+    name TEXT,
+    vin TEXT,
+    truck_id UUID REFERENCES trucks(id),
+    last_seen_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    UNIQUE (integration_id, provider_vehicle_id)
+);
+
+CREATE UNIQUE INDEX idx_telematics_vehicles_truck ON telematics_vehicles(integration_id, truck_id) WHERE truck_id IS NOT NULL;
+
+-- Deliveries already handled; providers retry and may send an event more
+-- than once.
+CREATE TABLE telematics_events (
+    integration_id UUID NOT NULL REFERENCES telematics_integrations(id) ON DELETE CASCADE,
+    event_id TEXT NOT NULL,
+    event_type TEXT NOT NULL,
+    received_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (integration_id, event_id)
+);
+
+-- Diagnostic trouble codes the engine reported. Open until the provider
+-- reports the code cleared.
+CREATE TABLE engine_faults (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    company_id UUID NOT NULL REFERENCES companies(id),
+    truck_id UUID NOT NULL REFERENCES trucks(id),
+    code TEXT NOT NULL,
+    description TEXT,
+    severity TEXT,
+    source TEXT NOT NULL,
+    occurred_at TIMESTAMPTZ NOT NULL,
+    cleared_at TIMESTAMPTZ,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE UNIQUE INDEX idx_engine_faults_open ON engine_faults(truck_id, code) WHERE cleared_at IS NULL;
+CREATE INDEX idx_engine_faults_company ON engine_faults(company_id, occurred_at DESC);
@@ -0,0 +1,22 @@
+-- ELD integrations can be polled through the provider's API as well as
+-- pushed to by webhook, and Geotab joins Samsara and Motive. The API token
+-- is the provider's key (Geotab: the API user's password); `settings`
+-- holds anything else the provider needs, such as Geotab's database and
+-- user name.
+
+ALTER TABLE telematics_integrations DROP CONSTRAINT telematics_integrations_provider_check;
+ALTER TABLE telematics_integrations ADD CONSTRAINT telematics_integrations_provider_check
+    CHECK (provider IN ('samsara', 'motive', 'geotab'));
+
+ALTER TABLE telematics_integrations ALTER COLUMN webhook_secret DROP NOT NULL;
+ALTER TABLE telematics_integrations
+    ADD COLUMN api_token TEXT,
+    ADD COLUMN settings JSONB NOT NULL DEFAULT '{}',
+    ADD COLUMN last_polled_at TIMESTAMPTZ,
+    ADD COLUMN last_poll_error TEXT;
+
+ALTER TABLE telematics_integrations ADD CONSTRAINT telematics_integrations_credentials_check
+    CHECK (webhook_secret IS NOT NULL OR api_token IS NOT NULL);
+
+CREATE INDEX idx_telematics_integrations_polled ON telematics_integrations(last_polled_at)
+    WHERE active AND api_token IS NOT NULL;
@@ -0,0 +1,30 @@
+-- Fuel-stop planning: each truck's tank and mileage, and the truck stops
+-- on the company's fuel card networks with their retail and discounted
+-- diesel prices as the card feeds report them.
+
+ALTER TABLE trucks
+    ADD COLUMN fuel_tank_gallons DOUBLE PRECISION CHECK (fuel_tank_gallons > 0),
+    ADD COLUMN average_mpg DOUBLE PRECISION CHECK (average_mpg > 0);
+
+CREATE TABLE fuel_stations (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    company_id UUID NOT NULL REFERENCES companies(id),
+    -- The card network the feed came from, e.g. comdata or efs.
+    network TEXT NOT NULL,
+    station_code TEXT NOT NULL,
+    name TEXT NOT NULL,
+    address TEXT,
+    city TEXT,
+    state TEXT,
+    latitude DOUBLE PRECISION NOT NULL,
+    longitude DOUBLE PRECISION NOT NULL,
+    retail_price NUMERIC(6, 3) NOT NULL CHECK (retail_price > 0),
+    -- What the company pays with its card, after the network discount.
+    discounted_price NUMERIC(6, 3) NOT NULL CHECK (discounted_price > 0),
+    price_effective_at TIMESTAMPTZ NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    UNIQUE (company_id, network, station_code)
+);
+
+CREATE INDEX idx_fuel_stations_position ON fuel_stations(company_id, latitude, longitude);
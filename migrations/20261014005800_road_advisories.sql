@@ -0,0 +1,29 @@
+-- Weather alerts and road closures found along the remaining route of a
+-- load on the road. A row stays active while the advisory is still issued
+-- and the route still crosses it; it's cleared once either stops.
+
+CREATE TABLE load_road_advisories (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    company_id UUID NOT NULL REFERENCES companies(id),
+    load_id UUID NOT NULL REFERENCES loads(id) ON DELETE CASCADE,
+    -- nws for National Weather Service alerts, state_dot for closures and
+    -- restrictions from state DOT work zone feeds.
+    source TEXT NOT NULL CHECK (source IN ('nws', 'state_dot')),
+    -- The advisory's id in its feed.
+    external_id TEXT NOT NULL,
+    event TEXT NOT NULL,
+    severity TEXT NOT NULL,
+    headline TEXT NOT NULL,
+    description TEXT,
+    starts_at TIMESTAMPTZ,
+    ends_at TIMESTAMPTZ,
+    -- The first point along the route inside the advisory.
+    latitude DOUBLE PRECISION NOT NULL,
+    longitude DOUBLE PRECISION NOT NULL,
+    detected_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    cleared_at TIMESTAMPTZ,
+    UNIQUE (load_id, source, external_id)
+);
+
+CREATE INDEX idx_load_road_advisories_active ON load_road_advisories(company_id, detected_at DESC) WHERE cleared_at IS NULL;
@@ -0,0 +1,30 @@
+-- An ETA for every stop still ahead of a load, not only the next one.
+-- Each carries the HOS rest and appointment wait that went into it;
+-- load_etas stays the summary for the next stop.
+
+ALTER TABLE load_etas
+    ADD COLUMN average_speed_mph DOUBLE PRECISION,
+    ADD COLUMN hos_rest_minutes DOUBLE PRECISION NOT NULL DEFAULT 0;
+
+CREATE TABLE load_stop_etas (
+    stop_id UUID PRIMARY KEY REFERENCES load_stops(id) ON DELETE CASCADE,
+    load_id UUID NOT NULL REFERENCES loads(id) ON DELETE CASCADE,
+    company_id UUID NOT NULL REFERENCES companies(id),
+    eta TIMESTAMPTZ NOT NULL,
+    -- After any wait for the appointment and the stop's service time.
+    departs_at TIMESTAMPTZ NOT NULL,
+    -- Road miles from the driver's position.
+    miles_remaining DOUBLE PRECISION NOT NULL,
+    -- Driving on the leg into the stop.
+    drive_minutes DOUBLE PRECISION NOT NULL,
+    -- Breaks, 10-hour resets and 34-hour restarts taken on the leg.
+    hos_rest_minutes DOUBLE PRECISION NOT NULL DEFAULT 0,
+    -- Arriving before the appointment opens.
+    wait_minutes DOUBLE PRECISION NOT NULL DEFAULT 0,
+    deadline TIMESTAMPTZ,
+    slack_minutes INTEGER,
+    at_risk BOOLEAN NOT NULL DEFAULT FALSE,
+    computed_at TIMESTAMPTZ NOT NULL
+);
+
+CREATE INDEX idx_load_stop_etas_load ON load_stop_etas(load_id);
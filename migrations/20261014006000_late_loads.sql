@@ -0,0 +1,39 @@
+-- Late-load monitoring. A load whose ETA leaves too little slack before an
+-- appointment gets an open alert, escalated from dispatch to operations
+-- management and then to the customer on the company's schedule, until
+-- dispatch resolves it with a reason code.
+
+ALTER TABLE companies
+    ADD COLUMN late_escalate_ops_minutes INTEGER NOT NULL DEFAULT 30
+        CHECK (late_escalate_ops_minutes > 0),
+    -- NULL keeps customers out of it.
+    ADD COLUMN late_notify_customer_minutes INTEGER DEFAULT 60
+        CHECK (late_notify_customer_minutes > 0);
+
+CREATE TABLE load_delay_alerts (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    company_id UUID NOT NULL REFERENCES companies(id),
+    load_id UUID NOT NULL REFERENCES loads(id) ON DELETE CASCADE,
+    -- The stop with the least slack when last checked.
+    stop_id UUID REFERENCES load_stops(id) ON DELETE SET NULL,
+    -- recovered: the ETA is back inside the window, though dispatch still
+    -- owes a reason.
+    status TEXT NOT NULL CHECK (status IN ('at_risk', 'late', 'recovered')),
+    eta TIMESTAMPTZ,
+    deadline TIMESTAMPTZ,
+    slack_minutes INTEGER,
+    opened_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    late_at TIMESTAMPTZ,
+    dispatcher_notified_at TIMESTAMPTZ,
+    ops_notified_at TIMESTAMPTZ,
+    customer_notified_at TIMESTAMPTZ,
+    reason_code TEXT,
+    resolution_notes TEXT,
+    resolved_by UUID REFERENCES users(id),
+    resolved_at TIMESTAMPTZ,
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    CHECK ((resolved_at IS NULL) = (reason_code IS NULL))
+);
+
+CREATE UNIQUE INDEX idx_load_delay_alerts_open ON load_delay_alerts(load_id) WHERE resolved_at IS NULL;
+CREATE INDEX idx_load_delay_alerts_company ON load_delay_alerts(company_id, opened_at DESC);
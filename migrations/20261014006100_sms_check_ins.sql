@@ -0,0 +1,22 @@
+-- Text messages with drivers who check in by SMS instead of the driver
+-- app: the prompts sent to them and the replies that move their loads.
+
+CREATE TABLE sms_messages (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    company_id UUID NOT NULL REFERENCES companies(id),
+    driver_id UUID NOT NULL REFERENCES drivers(id),
+    load_id UUID REFERENCES loads(id) ON DELETE SET NULL,
+    direction TEXT NOT NULL CHECK (direction IN ('inbound', 'outbound')),
+    -- The driver's number as Twilio has it, in E.164.
+    phone TEXT NOT NULL,
+    body TEXT NOT NULL,
+    -- Twilio's message SID; a redelivered reply is only handled once.
+    external_id TEXT,
+    -- What a reply did, e.g. "arrived at stop 2"; also why it did nothing.
+    outcome TEXT,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE UNIQUE INDEX idx_sms_messages_external ON sms_messages(external_id) WHERE external_id IS NOT NULL;
+CREATE INDEX idx_sms_messages_driver ON sms_messages(driver_id, created_at DESC);
+CREATE INDEX idx_sms_messages_load ON sms_messages(load_id, direction, created_at DESC);
@@ -0,0 +1,59 @@
+-- Load tenders brokers email in. Each message is parsed into a draft that
+-- waits for a dispatcher to book it as a load or decline it.
+
+CREATE TABLE tender_sender_rules (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    company_id UUID NOT NULL REFERENCES companies(id),
+    -- A full address, or a domain for everyone who sends from it.
+    -- Lower-cased; an address wins over its domain.
+    sender TEXT NOT NULL,
+    customer_id UUID NOT NULL REFERENCES customers(id) ON DELETE CASCADE,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    UNIQUE (company_id, sender)
+);
+
+CREATE TABLE email_tenders (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    company_id UUID NOT NULL REFERENCES companies(id),
+    -- received until the worker has parsed it, then pending_review until a
+    -- dispatcher accepts or declines it.
+    status TEXT NOT NULL DEFAULT 'received'
+        CHECK (status IN ('received', 'pending_review', 'accepted', 'declined')),
+    message_id TEXT NOT NULL,
+    sender TEXT NOT NULL,
+    subject TEXT NOT NULL,
+    body_text TEXT NOT NULL,
+    -- Where the inbound mail service is holding each attachment:
+    -- [{ "url", "name", "content_type", "size" }].
+    attachments JSONB NOT NULL DEFAULT '[]',
+    received_at TIMESTAMPTZ NOT NULL,
+    -- From the sender rules, when one matches.
+    customer_id UUID REFERENCES customers(id),
+    -- Read off the body and attachments by the worker.
+    attachment_text TEXT,
+    reference_number TEXT,
+    pickup_date DATE,
+    delivery_date DATE,
+    origin_city TEXT,
+    origin_state TEXT,
+    destination_city TEXT,
+    destination_state TEXT,
+    shipper_name TEXT,
+    consignee_name TEXT,
+    equipment_type TEXT,
+    total_weight_lbs INTEGER,
+    commodity_description TEXT,
+    offered_rate NUMERIC(12, 2),
+    -- What the worker couldn't find or read, for the reviewer.
+    parse_warnings TEXT[] NOT NULL DEFAULT '{}',
+    parsed_at TIMESTAMPTZ,
+    load_id UUID REFERENCES loads(id) ON DELETE SET NULL,
+    decline_reason TEXT,
+    reviewed_by UUID REFERENCES users(id),
+    reviewed_at TIMESTAMPTZ,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    UNIQUE (company_id, message_id)
+);
+
+CREATE INDEX idx_email_tenders_status ON email_tenders(company_id, status, received_at DESC);
+CREATE INDEX idx_email_tenders_unparsed ON email_tenders(received_at) WHERE status = 'received';
@@ -0,0 +1,37 @@
+-- Fields read by OCR off uploaded rate confirmations and BOLs. Nothing
+-- here touches the load until someone in the office confirms it.
+
+CREATE TABLE document_extractions (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    company_id UUID NOT NULL REFERENCES companies(id),
+    document_id UUID NOT NULL UNIQUE REFERENCES documents(id) ON DELETE CASCADE,
+    load_id UUID NOT NULL REFERENCES loads(id) ON DELETE CASCADE,
+    document_type TEXT NOT NULL,
+    status TEXT NOT NULL DEFAULT 'pending'
+        CHECK (status IN ('pending', 'extracted', 'failed', 'confirmed', 'dismissed')),
+    -- tesseract or google_vision, once read.
+    provider TEXT,
+    attempts INT NOT NULL DEFAULT 0,
+    raw_text TEXT,
+    reference_number TEXT,
+    bol_number TEXT,
+    pickup_date DATE,
+    delivery_date DATE,
+    origin_city TEXT,
+    origin_state TEXT,
+    destination_city TEXT,
+    destination_state TEXT,
+    total_weight_lbs INT,
+    -- Only ever a suggestion; rates change through the load itself.
+    rate NUMERIC(12, 2),
+    warnings TEXT[] NOT NULL DEFAULT '{}',
+    error TEXT,
+    extracted_at TIMESTAMPTZ,
+    reviewed_by UUID REFERENCES users(id),
+    reviewed_at TIMESTAMPTZ,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_document_extractions_pending ON document_extractions(created_at) WHERE status = 'pending';
+CREATE INDEX idx_document_extractions_load ON document_extractions(load_id, created_at DESC);
@@ -0,0 +1,51 @@
+-- Document versions, and per-company retention policies enforced by a
+-- scheduled purge that leaves an audit record for every file it removes.
+
+-- A re-upload becomes the next version of the document it replaces; the
+-- earlier versions stay downloadable but drop out of listings.
+ALTER TABLE documents
+    ADD COLUMN version INT NOT NULL DEFAULT 1,
+    -- The first version, on every later one.
+    ADD COLUMN original_id UUID REFERENCES documents(id) ON DELETE SET NULL,
+    ADD COLUMN superseded_at TIMESTAMPTZ,
+    -- Set once retention has removed the file; the metadata stays.
+    ADD COLUMN purged_at TIMESTAMPTZ;
+
+CREATE INDEX idx_documents_original ON documents(original_id) WHERE original_id IS NOT NULL;
+
+CREATE TABLE document_retention_policies (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    company_id UUID NOT NULL REFERENCES companies(id),
+    document_type TEXT NOT NULL,
+    -- What the retention period runs from: the upload itself, delivery of
+    -- the document's load, or termination of its driver.
+    retention_basis TEXT NOT NULL CHECK (retention_basis IN ('uploaded', 'load_delivered', 'driver_terminated')),
+    retain_years INT NOT NULL CHECK (retain_years BETWEEN 1 AND 99),
+    created_by UUID NOT NULL REFERENCES users(id),
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    UNIQUE (company_id, document_type)
+);
+
+-- Append-only: one row per file purged, kept after the policy that
+-- purged it is changed or removed.
+CREATE TABLE document_purges (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    company_id UUID NOT NULL REFERENCES companies(id),
+    document_id UUID NOT NULL,
+    policy_id UUID REFERENCES document_retention_policies(id) ON DELETE SET NULL,
+    document_type TEXT NOT NULL,
+    file_name TEXT NOT NULL,
+    version INT NOT NULL,
+    size_bytes INT NOT NULL,
+    load_id UUID,
+    driver_id UUID,
+    retention_basis TEXT NOT NULL,
+    retain_years INT NOT NULL,
+    -- When the retention period started.
+    retained_from TIMESTAMPTZ NOT NULL,
+    uploaded_at TIMESTAMPTZ NOT NULL,
+    purged_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_document_purges_company ON document_purges(company_id, purged_at DESC);
@@ -0,0 +1,93 @@
+-- Monthly partitions for location history, and archival of old completed
+-- loads.
+--
+-- Loads are referenced from dozens of tables, so an archived load keeps
+-- its row and is only flagged; what moves is its location history, by far
+-- the largest thing a load accumulates, into location_history_archive.
+-- Operational queries skip archived loads and the archive table, and
+-- reads that ask for them with include_archived see both.
+
+ALTER TABLE loads ADD COLUMN archived_at TIMESTAMPTZ;
+
+CREATE INDEX idx_loads_company_pickup_live ON loads(company_id, pickup_date) WHERE archived_at IS NULL;
+
+-- Creates the month's partition if it isn't there yet. The archival job
+-- keeps a few months ahead of the clock; the default partition only
+-- catches pings stamped beyond that.
+CREATE FUNCTION create_location_history_partition(for_month DATE) RETURNS VOID AS $$
+DECLARE
+    starts DATE := date_trunc('month', for_month)::date;
+BEGIN
+    EXECUTE format(
+        'CREATE TABLE IF NOT EXISTS %I PARTITION OF location_history FOR VALUES FROM (%L) TO (%L)',
+        'location_history_' || to_char(starts, 'YYYY_MM'),
+        starts,
+        (starts + INTERVAL '1 month')::date
+    );
+END;
+$$ LANGUAGE plpgsql;
+
+ALTER TABLE location_history RENAME TO location_history_unpartitioned;
+ALTER INDEX idx_location_history_truck RENAME TO idx_location_history_unpartitioned_truck;
+ALTER INDEX idx_location_history_driver RENAME TO idx_location_history_unpartitioned_driver;
+ALTER INDEX idx_location_history_load RENAME TO idx_location_history_unpartitioned_load;
+
+CREATE TABLE location_history (
+    id UUID NOT NULL DEFAULT gen_random_uuid(),
+    company_id UUID NOT NULL REFERENCES companies(id),
+    load_id UUID REFERENCES loads(id) ON DELETE SET NULL,
+    driver_id UUID REFERENCES drivers(id),
+    truck_id UUID REFERENCES trucks(id),
+    latitude DOUBLE PRECISION NOT NULL,
+    longitude DOUBLE PRECISION NOT NULL,
+    speed_mph DOUBLE PRECISION,
+    source TEXT NOT NULL,
+    recorded_at TIMESTAMPTZ NOT NULL,
+    PRIMARY KEY (id, recorded_at)
+) PARTITION BY RANGE (recorded_at);
+
+CREATE INDEX idx_location_history_truck ON location_history(truck_id, source, recorded_at DESC);
+CREATE INDEX idx_location_history_driver ON location_history(driver_id, recorded_at DESC);
+CREATE INDEX idx_location_history_load ON location_history(load_id, recorded_at);
+
+DO $$
+DECLARE
+    partition_month DATE;
+BEGIN
+    FOR partition_month IN
+        SELECT generate_series(
+            date_trunc('month', LEAST(COALESCE((SELECT MIN(recorded_at) FROM location_history_unpartitioned), NOW()), NOW())),
+            date_trunc('month', NOW()) + INTERVAL '3 months',
+            INTERVAL '1 month'
+        )::date
+    LOOP
+        PERFORM create_location_history_partition(partition_month);
+    END LOOP;
+END;
+$$;
+
+CREATE TABLE location_history_default PARTITION OF location_history DEFAULT;
+
+INSERT INTO location_history (id, company_id, load_id, driver_id, truck_id, latitude, longitude, speed_mph, source, recorded_at)
+SELECT id, company_id, load_id, driver_id, truck_id, latitude, longitude, speed_mph, source, recorded_at
+FROM location_history_unpartitioned;
+
+DROP TABLE location_history_unpartitioned;
+
+-- Pings of archived loads. No foreign keys, so archived history never
+-- holds up changes to the live tables.
+CREATE TABLE location_history_archive (
+    id UUID NOT NULL,
+    company_id UUID NOT NULL,
+    load_id UUID,
+    driver_id UUID,
+    truck_id UUID,
+    latitude DOUBLE PRECISION NOT NULL,
+    longitude DOUBLE PRECISION NOT NULL,
+    speed_mph DOUBLE PRECISION,
+    source TEXT NOT NULL,
+    recorded_at TIMESTAMPTZ NOT NULL,
+    archived_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_location_history_archive_load ON location_history_archive(load_id, recorded_at);
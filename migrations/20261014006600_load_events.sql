@@ -0,0 +1,57 @@
+-- The life of each load as one append-only stream. Each row is written
+-- alongside the change it records, so the timeline can be read back
+-- without piecing it together from stops, documents, tenders and
+-- invoices.
+
+CREATE TABLE load_events (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    company_id UUID NOT NULL REFERENCES companies(id),
+    load_id UUID NOT NULL REFERENCES loads(id) ON DELETE CASCADE,
+    event_type TEXT NOT NULL CHECK (event_type IN (
+        'created', 'tendered', 'dispatched', 'status_changed', 'arrived', 'departed',
+        'document_uploaded', 'invoiced', 'paid'
+    )),
+    -- The load's status once the event happened, for status changes.
+    status TEXT,
+    -- The stop, document, carrier or invoice the event is about.
+    subject_id UUID,
+    note TEXT,
+    occurred_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_load_events_load ON load_events(load_id, occurred_at);
+
+-- Seed existing loads from what their tables still show. Earlier status
+-- changes left nothing behind but the current status, so they're missing.
+INSERT INTO load_events (company_id, load_id, event_type, occurred_at)
+SELECT company_id, id, 'created', created_at FROM loads;
+
+INSERT INTO load_events (company_id, load_id, event_type, subject_id, note, occurred_at)
+SELECT t.company_id, t.load_id, 'tendered', t.carrier_id, 'Offered to ' || c.legal_name || ' at ' || t.carrier_rate, t.created_at
+FROM carrier_tenders t
+JOIN carriers c ON c.id = t.carrier_id;
+
+INSERT INTO load_events (company_id, load_id, event_type, subject_id, note, occurred_at)
+SELECT company_id, load_id, 'arrived', id, COALESCE(location_name, city), arrived_at
+FROM load_stops
+WHERE arrived_at IS NOT NULL;
+
+INSERT INTO load_events (company_id, load_id, event_type, subject_id, note, occurred_at)
+SELECT company_id, load_id, 'departed', id, COALESCE(location_name, city), departed_at
+FROM load_stops
+WHERE departed_at IS NOT NULL;
+
+INSERT INTO load_events (company_id, load_id, event_type, subject_id, note, occurred_at)
+SELECT company_id, load_id, 'document_uploaded', id, document_type || ': ' || file_name, created_at
+FROM documents
+WHERE load_id IS NOT NULL;
+
+INSERT INTO load_events (company_id, load_id, event_type, subject_id, note, occurred_at)
+SELECT company_id, load_id, 'invoiced', id, invoice_number, created_at
+FROM invoices
+WHERE load_id IS NOT NULL;
+
+INSERT INTO load_events (company_id, load_id, event_type, subject_id, note, occurred_at)
+SELECT company_id, load_id, 'paid', id, invoice_number, paid_at
+FROM invoices
+WHERE load_id IS NOT NULL AND status = 'paid' AND paid_at IS NOT NULL;
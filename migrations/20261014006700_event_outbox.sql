@@ -0,0 +1,20 @@
+-- Domain events waiting to go to the message broker. Rows are written in
+-- the same transaction as the change they describe, so a change that
+-- commits always has its event here, and the relay keeps offering each
+-- row until the broker takes it.
+
+CREATE TABLE event_outbox (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    company_id UUID NOT NULL REFERENCES companies(id),
+    -- The domain event, as serialized by the API.
+    event JSONB NOT NULL,
+    attempts INT NOT NULL DEFAULT 0,
+    last_error TEXT,
+    -- Pushed forward while a relay holds the row and after each failure.
+    next_attempt_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    delivered_at TIMESTAMPTZ,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_event_outbox_due ON event_outbox(next_attempt_at) WHERE delivered_at IS NULL;
+CREATE INDEX idx_event_outbox_delivered ON event_outbox(delivered_at) WHERE delivered_at IS NOT NULL;
@@ -0,0 +1,26 @@
+-- Company-defined fields on loads and customers, for the references each
+-- shipper wants carried. Values live in a JSONB column on the record,
+-- keyed by field name; the definitions say what each may hold.
+
+CREATE TABLE custom_field_definitions (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    company_id UUID NOT NULL REFERENCES companies(id),
+    entity_type TEXT NOT NULL CHECK (entity_type IN ('load', 'customer')),
+    -- The key the value is stored and sent under.
+    name TEXT NOT NULL CHECK (name ~ '^[a-z][a-z0-9_]{0,62}$'),
+    label TEXT NOT NULL,
+    field_type TEXT NOT NULL CHECK (field_type IN ('text', 'number', 'date', 'boolean', 'select')),
+    -- The choices of a select field.
+    options TEXT[] NOT NULL DEFAULT '{}',
+    required BOOLEAN NOT NULL DEFAULT FALSE,
+    -- Order on screens, exports and documents.
+    position INT NOT NULL DEFAULT 0,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    UNIQUE (company_id, entity_type, name)
+);
+
+ALTER TABLE loads ADD COLUMN custom_fields JSONB NOT NULL DEFAULT '{}';
+ALTER TABLE customers ADD COLUMN custom_fields JSONB NOT NULL DEFAULT '{}';
+
+CREATE INDEX idx_loads_custom_fields ON loads USING GIN (custom_fields jsonb_path_ops);
@@ -0,0 +1,38 @@
+-- Company-defined load statuses, such as "at shipper" or "loaded, awaiting
+-- paperwork". Each one sits inside a core phase: the load's status column
+-- keeps the phase, so dispatch checks and reports go on working from it,
+-- and custom_status says where within the phase the load is.
+
+CREATE TABLE load_status_definitions (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    company_id UUID NOT NULL REFERENCES companies(id),
+    -- What the status is set and shown as on the load.
+    name TEXT NOT NULL CHECK (name ~ '^[a-z][a-z0-9_]{0,62}$'),
+    label TEXT NOT NULL,
+    phase TEXT NOT NULL CHECK (phase IN (
+        'pending', 'dispatched', 'accepted', 'in_transit', 'delivered', 'completed', 'cancelled'
+    )),
+    -- Order within the phase on screens.
+    position INT NOT NULL DEFAULT 0,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    UNIQUE (company_id, name)
+);
+
+ALTER TABLE loads ADD COLUMN custom_status TEXT;
+
+-- A load that leaves the phase by any path other than setting a custom
+-- status, such as being dispatched or a driver accepting, drops the
+-- custom status it had.
+CREATE FUNCTION clear_stale_custom_status() RETURNS TRIGGER AS $$
+BEGIN
+    IF NEW.status IS DISTINCT FROM OLD.status AND NEW.custom_status IS NOT DISTINCT FROM OLD.custom_status THEN
+        NEW.custom_status := NULL;
+    END IF;
+    RETURN NEW;
+END;
+$$ LANGUAGE plpgsql;
+
+CREATE TRIGGER loads_clear_stale_custom_status
+    BEFORE UPDATE OF status ON loads
+    FOR EACH ROW EXECUTE FUNCTION clear_stale_custom_status();
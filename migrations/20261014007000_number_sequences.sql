@@ -0,0 +1,15 @@
+-- Per-company numbering for loads and invoices. A number is taken from
+-- the row inside the transaction that uses it, so concurrent requests
+-- wait their turn rather than share a number, and a rolled-back request
+-- gives its number back instead of leaving a gap.
+
+CREATE TABLE number_sequences (
+    company_id UUID NOT NULL REFERENCES companies(id),
+    document_type TEXT NOT NULL CHECK (document_type IN ('load', 'invoice')),
+    prefix TEXT NOT NULL DEFAULT '',
+    -- Digits the value is zero-padded to.
+    padding INT NOT NULL DEFAULT 6 CHECK (padding BETWEEN 1 AND 12),
+    next_value BIGINT NOT NULL DEFAULT 1 CHECK (next_value > 0),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (company_id, document_type)
+);
@@ -0,0 +1,28 @@
+-- Company guardrails on loads, checked when a load is created, updated or
+-- dispatched. A broken rule of severity 'error' stops the change; one of
+-- severity 'warning' lets it through and is reported back.
+
+CREATE TABLE validation_rules (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    company_id UUID NOT NULL REFERENCES companies(id),
+    name TEXT NOT NULL,
+    event TEXT NOT NULL CHECK (event IN ('create', 'update', 'dispatch')),
+    rule_type TEXT NOT NULL CHECK (rule_type IN ('max_weight', 'min_rate_per_mile', 'required_field')),
+    -- Limits the rule to loads of this equipment type.
+    equipment_type TEXT,
+    -- The weight limit in pounds, or the minimum customer rate per mile.
+    threshold NUMERIC(12, 2),
+    -- The field a required_field rule asks for.
+    field TEXT,
+    severity TEXT NOT NULL DEFAULT 'error' CHECK (severity IN ('error', 'warning')),
+    -- Shown in place of the generated message.
+    message TEXT,
+    active BOOLEAN NOT NULL DEFAULT TRUE,
+    created_by UUID NOT NULL REFERENCES users(id),
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    CHECK (rule_type = 'required_field' OR threshold IS NOT NULL),
+    CHECK (rule_type <> 'required_field' OR field IS NOT NULL)
+);
+
+CREATE INDEX idx_validation_rules_company ON validation_rules(company_id, event) WHERE active;
@@ -0,0 +1,37 @@
+-- Company user administration: email invitations accepted through a
+-- tokenized link, deactivation, and when each account was last active.
+
+ALTER TABLE users
+    -- Touched at most once a minute while the account is in use.
+    ADD COLUMN last_active_at TIMESTAMPTZ,
+    ADD COLUMN deactivated_at TIMESTAMPTZ,
+    ADD COLUMN deactivated_by UUID REFERENCES users(id);
+
+CREATE TABLE user_invitations (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    company_id UUID NOT NULL REFERENCES companies(id),
+    email TEXT NOT NULL,
+    role TEXT NOT NULL,
+    first_name TEXT,
+    last_name TEXT,
+    -- Only the hash of the link's token is kept.
+    token_hash TEXT NOT NULL UNIQUE,
+    status TEXT NOT NULL DEFAULT 'pending' CHECK (status IN ('pending', 'accepted', 'revoked')),
+    expires_at TIMESTAMPTZ NOT NULL,
+    invited_by UUID NOT NULL REFERENCES users(id),
+    -- The account created when the invitation was accepted.
+    user_id UUID REFERENCES users(id),
+    accepted_at TIMESTAMPTZ,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE UNIQUE INDEX idx_user_invitations_pending ON user_invitations(company_id, lower(email)) WHERE status = 'pending';
+
+-- Managing users takes the admin role. Each company's longest-standing
+-- office account gets it so someone can hand it on.
+UPDATE users SET role = 'admin', updated_at = NOW()
+WHERE id IN (
+    SELECT DISTINCT ON (company_id) id FROM users
+    WHERE status = 'active' AND role NOT IN ('driver', 'customer', 'carrier')
+    ORDER BY company_id, created_at
+);
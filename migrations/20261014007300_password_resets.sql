@@ -0,0 +1,22 @@
+-- Forgot-password links. Each row is also the audit record of the reset:
+-- who asked, from where, and whether and from where the password was
+-- changed with it. Rows are kept after they're used or lapse.
+
+CREATE TABLE password_resets (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    company_id UUID NOT NULL REFERENCES companies(id),
+    user_id UUID NOT NULL REFERENCES users(id),
+    -- Only the hash of the link's token is kept.
+    token_hash TEXT NOT NULL UNIQUE,
+    expires_at TIMESTAMPTZ NOT NULL,
+    requested_ip TEXT,
+    requested_user_agent TEXT,
+    -- Set when the password was changed with the link.
+    used_at TIMESTAMPTZ,
+    used_ip TEXT,
+    -- Set when a newer link replaced this one before it was used.
+    superseded_at TIMESTAMPTZ,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_password_resets_user ON password_resets(user_id, created_at DESC);
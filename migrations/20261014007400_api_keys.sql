@@ -0,0 +1,43 @@
+-- Company API keys for integrations. Each key is limited to explicit
+-- scopes and optionally to client address ranges; requests a key isn't
+-- allowed to make are refused and recorded for the company's admins.
+
+CREATE TABLE api_keys (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    company_id UUID NOT NULL REFERENCES companies(id),
+    name TEXT NOT NULL,
+    -- Only the hash of the key is kept; the hint is its last characters,
+    -- for telling keys apart.
+    key_hash TEXT NOT NULL UNIQUE,
+    key_hint TEXT NOT NULL,
+    -- `<resource>:read` or `<resource>:write`, e.g. 'loads:read'.
+    scopes TEXT[] NOT NULL DEFAULT '{}',
+    -- Address ranges the key may be used from, e.g. '203.0.113.0/24'.
+    -- Empty allows any address.
+    allowed_cidrs TEXT[] NOT NULL DEFAULT '{}',
+    created_by UUID NOT NULL REFERENCES users(id),
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    last_used_at TIMESTAMPTZ,
+    last_used_ip TEXT,
+    revoked_at TIMESTAMPTZ,
+    revoked_by UUID REFERENCES users(id)
+);
+
+CREATE INDEX idx_api_keys_company ON api_keys(company_id);
+
+-- Append-only: one row per refused request.
+CREATE TABLE api_key_denials (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    company_id UUID NOT NULL REFERENCES companies(id),
+    api_key_id UUID NOT NULL REFERENCES api_keys(id) ON DELETE CASCADE,
+    reason TEXT NOT NULL CHECK (reason IN ('ip_not_allowed', 'scope_missing', 'revoked')),
+    ip TEXT,
+    method TEXT NOT NULL,
+    path TEXT NOT NULL,
+    required_scope TEXT,
+    occurred_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_api_key_denials_company ON api_key_denials(company_id, occurred_at DESC);
+CREATE INDEX idx_api_key_denials_key ON api_key_denials(api_key_id, occurred_at DESC);
@@ -0,0 +1,8 @@
+-- Anonymization of former drivers once their records no longer have to be
+-- kept. The driver's row stays so loads, settlements and payroll still
+-- add up; what identifies the person is cleared from it and from the
+-- records hung off it.
+
+ALTER TABLE drivers
+    ADD COLUMN anonymized_at TIMESTAMPTZ,
+    ADD COLUMN anonymized_by UUID REFERENCES users(id);
@@ -0,0 +1,70 @@
+-- Owner-operators leased on under percentage leases: the contractor's
+-- share of each load's revenue, fixed weekly deductions and an escrow
+-- fund, all posted to their weekly settlements.
+
+CREATE TABLE lease_agreements (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    company_id UUID NOT NULL REFERENCES companies(id),
+    driver_id UUID NOT NULL REFERENCES drivers(id),
+    status TEXT NOT NULL DEFAULT 'active' CHECK (status IN ('active', 'ended')),
+    starts_on DATE NOT NULL,
+    ends_on DATE,
+    -- The contractor's share of each delivered load's revenue.
+    revenue_share_percent NUMERIC(5, 2) NOT NULL CHECK (revenue_share_percent > 0 AND revenue_share_percent <= 100),
+    -- Held back from each settlement until the balance reaches the target.
+    escrow_target NUMERIC(12, 2) NOT NULL DEFAULT 0 CHECK (escrow_target >= 0),
+    escrow_per_settlement NUMERIC(12, 2) NOT NULL DEFAULT 0 CHECK (escrow_per_settlement >= 0),
+    escrow_balance NUMERIC(12, 2) NOT NULL DEFAULT 0 CHECK (escrow_balance >= 0),
+    created_by UUID NOT NULL REFERENCES users(id),
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    CHECK (ends_on IS NULL OR ends_on >= starts_on)
+);
+
+CREATE UNIQUE INDEX idx_lease_agreements_active ON lease_agreements(driver_id) WHERE status = 'active';
+CREATE INDEX idx_lease_agreements_company ON lease_agreements(company_id, status);
+
+-- Charged on every settlement week the lease covers, e.g. insurance or
+-- trailer rental.
+CREATE TABLE lease_deductions (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    lease_id UUID NOT NULL REFERENCES lease_agreements(id) ON DELETE CASCADE,
+    description TEXT NOT NULL,
+    amount NUMERIC(12, 2) NOT NULL CHECK (amount > 0),
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_lease_deductions_lease ON lease_deductions(lease_id);
+
+-- One row per lease and settlement week once its deductions and escrow
+-- contribution are on the settlement.
+CREATE TABLE lease_settlement_weeks (
+    lease_id UUID NOT NULL REFERENCES lease_agreements(id),
+    week_start DATE NOT NULL,
+    deductions NUMERIC(12, 2) NOT NULL,
+    escrow_contribution NUMERIC(12, 2) NOT NULL,
+    posted_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (lease_id, week_start)
+);
+
+-- Append-only. Amounts are signed as they move the escrow balance:
+-- contributions and interest add, withdrawals and refunds take away.
+CREATE TABLE escrow_transactions (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    company_id UUID NOT NULL REFERENCES companies(id),
+    lease_id UUID NOT NULL REFERENCES lease_agreements(id),
+    driver_id UUID NOT NULL REFERENCES drivers(id),
+    transaction_type TEXT NOT NULL CHECK (transaction_type IN ('contribution', 'withdrawal', 'interest', 'refund')),
+    amount NUMERIC(12, 2) NOT NULL,
+    balance_after NUMERIC(12, 2) NOT NULL,
+    -- The settlement line a contribution or refund was posted as.
+    settlement_line_id UUID REFERENCES settlement_lines(id) ON DELETE SET NULL,
+    note TEXT,
+    recorded_by UUID REFERENCES users(id),
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_escrow_transactions_driver ON escrow_transactions(driver_id, created_at);
+
+-- A load's revenue share is posted once.
+CREATE UNIQUE INDEX idx_settlement_lines_lease_revenue ON settlement_lines(load_id) WHERE line_type = 'lease_revenue';
@@ -0,0 +1,21 @@
+-- The paperwork a load needs on file before it can be billed, set per
+-- customer and load type. A load needs every document type listed on any
+-- checklist that matches it; a checklist without a customer or load type
+-- matches all of them.
+
+CREATE TABLE document_checklists (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    company_id UUID NOT NULL REFERENCES companies(id),
+    customer_id UUID REFERENCES customers(id) ON DELETE CASCADE,
+    load_type TEXT,
+    document_types TEXT[] NOT NULL CHECK (cardinality(document_types) > 0),
+    created_by UUID NOT NULL REFERENCES users(id),
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE UNIQUE INDEX idx_document_checklists_scope ON document_checklists(
+    company_id,
+    COALESCE(customer_id, '00000000-0000-0000-0000-000000000000'::UUID),
+    COALESCE(load_type, '')
+);
@@ -0,0 +1,40 @@
+-- Per-driver pay rules the settlement engine applies to each delivered
+-- load: a per-mile scale that slides with the length of haul, pay for
+-- extra stops, a share of the detention billed to the customer and a
+-- premium for New York City stops. Drivers without rules aren't paid
+-- per load through settlements, as before.
+
+CREATE TABLE driver_pay_rules (
+    driver_id UUID PRIMARY KEY REFERENCES drivers(id),
+    company_id UUID NOT NULL REFERENCES companies(id),
+    -- Paid for each stop beyond the first pickup and the final delivery.
+    stop_pay NUMERIC(10, 2) NOT NULL DEFAULT 0 CHECK (stop_pay >= 0),
+    detention_share_percent NUMERIC(5, 2) NOT NULL DEFAULT 0
+        CHECK (detention_share_percent >= 0 AND detention_share_percent <= 100),
+    nyc_premium NUMERIC(10, 2) NOT NULL DEFAULT 0 CHECK (nyc_premium >= 0),
+    updated_by UUID NOT NULL REFERENCES users(id),
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+-- A load's loaded miles pick the band with the highest min_miles at or
+-- below them, and every mile is paid at that band's rate.
+CREATE TABLE driver_mileage_bands (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    driver_id UUID NOT NULL REFERENCES driver_pay_rules(driver_id) ON DELETE CASCADE,
+    min_miles INT NOT NULL CHECK (min_miles >= 0),
+    rate_per_mile NUMERIC(8, 4) NOT NULL CHECK (rate_per_mile > 0),
+    UNIQUE (driver_id, min_miles)
+);
+
+-- One row per load once its pay is on the driver's settlement.
+CREATE TABLE load_driver_pay (
+    load_id UUID PRIMARY KEY REFERENCES loads(id),
+    company_id UUID NOT NULL REFERENCES companies(id),
+    driver_id UUID NOT NULL REFERENCES drivers(id),
+    loaded_miles DOUBLE PRECISION,
+    amount NUMERIC(12, 2) NOT NULL,
+    posted_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_load_driver_pay_driver ON load_driver_pay(driver_id, posted_at);
@@ -0,0 +1,6 @@
+-- The rep credited with each load in margin reporting. New loads go to
+-- whoever booked them; earlier loads stay unassigned until set.
+
+ALTER TABLE loads ADD COLUMN sales_rep_id UUID REFERENCES users(id);
+
+CREATE INDEX idx_loads_sales_rep ON loads(company_id, sales_rep_id, pickup_date);
@@ -0,0 +1,9 @@
+-- What trucks, trailers and drivers can do, and what a load needs of
+-- them. Dispatch checks the load's requirements against the driver and
+-- equipment put on it instead of leaving it to the dispatcher's memory.
+
+ALTER TABLE trucks ADD COLUMN capabilities TEXT[] NOT NULL DEFAULT '{}';
+ALTER TABLE trailers ADD COLUMN capabilities TEXT[] NOT NULL DEFAULT '{}';
+-- Gear the driver carries, and whether they run as a team.
+ALTER TABLE drivers ADD COLUMN capabilities TEXT[] NOT NULL DEFAULT '{}';
+ALTER TABLE loads ADD COLUMN required_capabilities TEXT[] NOT NULL DEFAULT '{}';
@@ -0,0 +1,76 @@
+-- Tracking of loads handed to partner carriers. A company connects its
+-- visibility network accounts; each load tendered out can then be
+-- subscribed with the network, which is polled and can push to a signed
+-- webhook, or given a direct push URL the partner's own system posts to.
+-- Updates are mapped onto the load's stops, location history and
+-- timeline.
+
+CREATE TABLE tracking_networks (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    company_id UUID NOT NULL REFERENCES companies(id),
+    provider TEXT NOT NULL CHECK (provider IN ('project44', 'fourkites')),
+    api_token TEXT NOT NULL,
+    -- Authenticates the network's pushes; without it only polling works.
+    webhook_secret TEXT,
+    active BOOLEAN NOT NULL DEFAULT TRUE,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    UNIQUE (company_id, provider)
+);
+
+CREATE TABLE tracking_subscriptions (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    company_id UUID NOT NULL REFERENCES companies(id),
+    load_id UUID NOT NULL REFERENCES loads(id) ON DELETE CASCADE,
+    carrier_id UUID NOT NULL REFERENCES carriers(id),
+    -- A network, or 'direct' for pushes from the partner's own system.
+    provider TEXT NOT NULL CHECK (provider IN ('project44', 'fourkites', 'direct')),
+    -- The network's id for the shipment.
+    external_id TEXT,
+    -- Only the hash of a direct push URL's token is kept.
+    token_hash TEXT UNIQUE,
+    status TEXT NOT NULL DEFAULT 'active' CHECK (status IN ('active', 'ended')),
+    -- The partner's latest status, as they sent it.
+    last_status TEXT,
+    last_update_at TIMESTAMPTZ,
+    last_polled_at TIMESTAMPTZ,
+    -- Why the last poll failed; cleared by the next one that succeeds.
+    last_poll_error TEXT,
+    created_by UUID REFERENCES users(id),
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    ended_at TIMESTAMPTZ
+);
+
+CREATE UNIQUE INDEX idx_tracking_subscriptions_active ON tracking_subscriptions(load_id) WHERE status = 'active';
+CREATE UNIQUE INDEX idx_tracking_subscriptions_external ON tracking_subscriptions(company_id, provider, external_id)
+    WHERE external_id IS NOT NULL;
+
+-- Every update received, as sent and as it was taken.
+CREATE TABLE tracking_updates (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    company_id UUID NOT NULL REFERENCES companies(id),
+    subscription_id UUID NOT NULL REFERENCES tracking_subscriptions(id) ON DELETE CASCADE,
+    load_id UUID NOT NULL REFERENCES loads(id) ON DELETE CASCADE,
+    -- The partner's id for the update; pushes and polls that repeat one
+    -- are dropped.
+    external_event_id TEXT,
+    external_status TEXT NOT NULL,
+    update_type TEXT NOT NULL CHECK (update_type IN ('position', 'arrived', 'departed', 'status')),
+    stop_sequence INT,
+    latitude DOUBLE PRECISION,
+    longitude DOUBLE PRECISION,
+    occurred_at TIMESTAMPTZ NOT NULL,
+    received_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE UNIQUE INDEX idx_tracking_updates_event ON tracking_updates(subscription_id, external_event_id)
+    WHERE external_event_id IS NOT NULL;
+CREATE INDEX idx_tracking_updates_load ON tracking_updates(load_id, occurred_at);
+
+-- Partner statuses that aren't a stop arrival or departure still show on
+-- the timeline.
+ALTER TABLE load_events DROP CONSTRAINT load_events_event_type_check;
+ALTER TABLE load_events ADD CONSTRAINT load_events_event_type_check CHECK (event_type IN (
+    'created', 'tendered', 'dispatched', 'status_changed', 'arrived', 'departed',
+    'document_uploaded', 'invoiced', 'paid', 'tracking'
+));
@@ -0,0 +1,50 @@
+-- How each customer takes its invoices: emailed with the POD attached,
+-- as an EDI 210, or uploaded to their AP portal. With auto_send on,
+-- invoices go out by themselves once the load is delivered and its
+-- required documents are in. Every attempt is kept, along with what the
+-- mail provider later reported about it.
+
+CREATE TABLE customer_invoice_delivery (
+    customer_id UUID PRIMARY KEY REFERENCES customers(id) ON DELETE CASCADE,
+    company_id UUID NOT NULL REFERENCES companies(id),
+    method TEXT NOT NULL CHECK (method IN ('email', 'edi_210', 'portal')),
+    -- Falls back to the customer's own email when empty.
+    email_to TEXT[] NOT NULL DEFAULT '{}',
+    -- Attaches the invoice's POD bundle to the email.
+    attach_documents BOOLEAN NOT NULL DEFAULT TRUE,
+    -- The trading partner's ISA receiver id and where 210s are posted.
+    edi_receiver_id TEXT,
+    edi_url TEXT,
+    portal_url TEXT,
+    portal_token TEXT,
+    portal_format TEXT CHECK (portal_format IN ('csv', 'json')),
+    auto_send BOOLEAN NOT NULL DEFAULT TRUE,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE TABLE invoice_deliveries (
+    id UUID PRIMARY KEY,
+    company_id UUID NOT NULL REFERENCES companies(id),
+    invoice_id UUID NOT NULL REFERENCES invoices(id) ON DELETE CASCADE,
+    customer_id UUID NOT NULL REFERENCES customers(id),
+    method TEXT NOT NULL CHECK (method IN ('email', 'edi_210', 'portal')),
+    -- The addresses mailed, or the URL posted to.
+    recipient TEXT NOT NULL,
+    -- Email stays 'sent' until the provider reports delivery or a bounce;
+    -- a post the endpoint accepted is 'delivered' straight away.
+    status TEXT NOT NULL CHECK (status IN ('sent', 'delivered', 'bounced', 'failed')),
+    provider_message_id TEXT,
+    -- The 210's interchange control number.
+    control_number BIGINT,
+    error TEXT,
+    -- Empty when the auto-send job sent it.
+    sent_by UUID REFERENCES users(id),
+    sent_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    confirmed_at TIMESTAMPTZ
+);
+
+CREATE INDEX idx_invoice_deliveries_invoice ON invoice_deliveries(invoice_id, sent_at);
+CREATE INDEX idx_invoice_deliveries_status ON invoice_deliveries(company_id, status, sent_at);
+
+CREATE SEQUENCE edi_interchange_control_numbers MAXVALUE 999999999 CYCLE;
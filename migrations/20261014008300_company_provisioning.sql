@@ -0,0 +1,36 @@
+-- Company signup and provisioning. A company's plan limits are copied
+-- onto its row, in the directory and in the region its data lives in, so
+-- they are checked next to the data and can be raised for one company
+-- without inventing a plan. Preferences the apps read, such as the time
+-- zone and units, are kept in settings and seeded for the region.
+
+ALTER TABLE companies
+    -- Companies from before plans keep no limits.
+    ADD COLUMN plan TEXT NOT NULL DEFAULT 'enterprise',
+    -- Active office accounts; drivers and portal users don't count. NULL
+    -- is unlimited.
+    ADD COLUMN max_users INTEGER CHECK (max_users > 0),
+    ADD COLUMN max_trucks INTEGER CHECK (max_trucks > 0),
+    ADD COLUMN settings JSONB NOT NULL DEFAULT '{}';
+
+-- Trucks come in through imports and integrations as well as the API, so
+-- the limit is held here rather than on each path. The company row is
+-- locked so two inserts can't both take the last place.
+CREATE FUNCTION enforce_truck_limit() RETURNS TRIGGER AS $$
+DECLARE
+    truck_limit INTEGER;
+BEGIN
+    SELECT max_trucks INTO truck_limit FROM companies WHERE id = NEW.company_id FOR UPDATE;
+    IF truck_limit IS NOT NULL
+        AND (SELECT COUNT(*) FROM trucks WHERE company_id = NEW.company_id AND status = 'active') >= truck_limit
+    THEN
+        RAISE EXCEPTION 'The company''s plan allows % active trucks', truck_limit
+            USING ERRCODE = 'check_violation';
+    END IF;
+    RETURN NEW;
+END;
+$$ LANGUAGE plpgsql;
+
+CREATE TRIGGER trucks_enforce_limit
+    BEFORE INSERT ON trucks
+    FOR EACH ROW EXECUTE FUNCTION enforce_truck_limit();
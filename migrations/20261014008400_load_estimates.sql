@@ -0,0 +1,6 @@
+-- Load profitability estimates. Fuel and driver pay are worked out per
+-- lane, so what's left to charge per mile is the truck's fixed cost:
+-- payments, insurance, permits and upkeep. It sits next to the all-in
+-- operating cost trips are costed with, and is kept apart from it so an
+-- estimate doesn't count fuel and pay twice.
+ALTER TABLE companies ADD COLUMN fixed_cost_per_mile NUMERIC(8, 4);
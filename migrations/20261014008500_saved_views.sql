@@ -0,0 +1,57 @@
+-- Named load and invoice searches. The filters are the search's own query
+-- parameters, so a view runs exactly as the search would. A view belongs
+-- to the user who saved it; shared ones can be run and subscribed to by
+-- the rest of the company.
+
+CREATE TABLE saved_views (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    company_id UUID NOT NULL REFERENCES companies(id),
+    user_id UUID NOT NULL REFERENCES users(id),
+    name TEXT NOT NULL,
+    resource TEXT NOT NULL CHECK (resource IN ('loads', 'invoices')),
+    filters JSONB NOT NULL DEFAULT '{}',
+    shared BOOLEAN NOT NULL DEFAULT FALSE,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    UNIQUE (user_id, name)
+);
+
+CREATE INDEX idx_saved_views_company ON saved_views(company_id, resource);
+
+-- A view's results mailed to the subscriber as CSV, daily or on one day
+-- of the week, at a local hour.
+CREATE TABLE report_subscriptions (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    company_id UUID NOT NULL REFERENCES companies(id),
+    view_id UUID NOT NULL REFERENCES saved_views(id) ON DELETE CASCADE,
+    user_id UUID NOT NULL REFERENCES users(id),
+    frequency TEXT NOT NULL CHECK (frequency IN ('daily', 'weekly')),
+    -- ISO weekday, Monday 1 to Sunday 7; weekly only.
+    weekday INTEGER CHECK (weekday BETWEEN 1 AND 7),
+    hour INTEGER NOT NULL CHECK (hour BETWEEN 0 AND 23),
+    timezone TEXT NOT NULL,
+    -- No email when the view comes back empty.
+    skip_empty BOOLEAN NOT NULL DEFAULT FALSE,
+    next_run_at TIMESTAMPTZ NOT NULL,
+    last_sent_at TIMESTAMPTZ,
+    last_row_count INTEGER,
+    last_error TEXT,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    CHECK ((frequency = 'weekly') = (weekday IS NOT NULL)),
+    UNIQUE (view_id, user_id)
+);
+
+CREATE INDEX idx_report_subscriptions_due ON report_subscriptions(next_run_at);
+
+-- The first time after `after` that it's `hour` o'clock in `tz`, on
+-- `weekday` when one is given. Worked out on the local calendar, so the
+-- hour holds across daylight saving changes.
+CREATE FUNCTION report_next_run(tz TEXT, hour INTEGER, weekday INTEGER, after TIMESTAMPTZ)
+RETURNS TIMESTAMPTZ AS $$
+    SELECT MIN(run_at) FROM (
+        SELECT ((after AT TIME ZONE tz)::date + days + make_interval(hours => hour)) AT TIME ZONE tz AS run_at
+        FROM generate_series(0, 8) AS days
+    ) candidates
+    WHERE run_at > after
+    AND (weekday IS NULL OR EXTRACT(ISODOW FROM run_at AT TIME ZONE tz) = weekday);
+$$ LANGUAGE sql STABLE;
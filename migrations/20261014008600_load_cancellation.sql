@@ -0,0 +1,31 @@
+-- Load cancellation. A cancelled load records why, who cancelled it and
+-- what it let go of: the driver, truck and trailer it held and the trip
+-- it was on. A customer that cancels after a truck was ordered is charged
+-- truck-ordered-not-used (TONU) per its contract's terms.
+
+ALTER TABLE customer_contracts
+    -- NULL means the contract charges no TONU.
+    ADD COLUMN tonu_amount NUMERIC(10, 2) CHECK (tonu_amount > 0),
+    -- Cancelling at least this long before the first pickup is free.
+    -- NULL charges any cancellation once a truck was ordered.
+    ADD COLUMN tonu_notice_hours INTEGER CHECK (tonu_notice_hours > 0);
+
+CREATE TABLE load_cancellations (
+    load_id UUID PRIMARY KEY REFERENCES loads(id) ON DELETE CASCADE,
+    company_id UUID NOT NULL REFERENCES companies(id),
+    reason_code TEXT NOT NULL,
+    notes TEXT,
+    -- The load's status when it was cancelled.
+    previous_status TEXT NOT NULL,
+    released_driver_id UUID REFERENCES drivers(id) ON DELETE SET NULL,
+    released_truck_id UUID REFERENCES trucks(id) ON DELETE SET NULL,
+    released_trailer_id UUID REFERENCES trailers(id) ON DELETE SET NULL,
+    released_trip_id UUID REFERENCES trips(id) ON DELETE SET NULL,
+    tonu_accessorial_id UUID REFERENCES load_accessorials(id) ON DELETE SET NULL,
+    -- Set when a TONU was owed under the contract but not charged.
+    tonu_waived BOOLEAN NOT NULL DEFAULT FALSE,
+    cancelled_by UUID NOT NULL REFERENCES users(id),
+    cancelled_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_load_cancellations_company ON load_cancellations(company_id, cancelled_at DESC);
@@ -0,0 +1,28 @@
+-- Driver qualification files under 49 CFR Part 391. Each record puts one
+-- document on file for a driver, with the date it speaks to: when the
+-- application was signed, the MVR pulled, the road test taken, the
+-- medical exam done or the prior employer answered. Medical cards carry
+-- their expiry; the MVR is due again a year after it was pulled.
+
+CREATE TABLE driver_qualification_records (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    company_id UUID NOT NULL REFERENCES companies(id),
+    driver_id UUID NOT NULL REFERENCES drivers(id),
+    item_type TEXT NOT NULL CHECK (item_type IN (
+        'application', 'mvr_report', 'road_test_certificate', 'medical_card', 'previous_employer_verification'
+    )),
+    document_id UUID NOT NULL REFERENCES documents(id),
+    effective_on DATE NOT NULL,
+    expires_on DATE CHECK (expires_on > effective_on),
+    -- The employer a verification came from. Unset on verifications
+    -- carried over from the driver's application.
+    employer_name TEXT,
+    notes TEXT,
+    recorded_by UUID NOT NULL REFERENCES users(id),
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    CHECK (item_type <> 'medical_card' OR expires_on IS NOT NULL)
+);
+
+CREATE INDEX idx_driver_qualification_records_driver
+    ON driver_qualification_records(driver_id, item_type, effective_on DESC);
+CREATE INDEX idx_driver_qualification_records_company ON driver_qualification_records(company_id);
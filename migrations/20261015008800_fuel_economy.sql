@@ -0,0 +1,57 @@
+-- Fuel economy and idle time from the ELD's engine counters: odometer,
+-- fuel burned, engine hours and idle hours, each a lifetime total. Only
+-- the latest counters are kept per truck; what the truck covered and
+-- burned between two readings is added to the day of the later one,
+-- under the driver on its load at the time.
+
+CREATE TABLE engine_counters (
+    truck_id UUID PRIMARY KEY REFERENCES trucks(id),
+    company_id UUID NOT NULL REFERENCES companies(id),
+    odometer_miles DOUBLE PRECISION NOT NULL CHECK (odometer_miles >= 0),
+    fuel_used_gallons DOUBLE PRECISION NOT NULL CHECK (fuel_used_gallons >= 0),
+    engine_hours DOUBLE PRECISION CHECK (engine_hours >= 0),
+    idle_hours DOUBLE PRECISION CHECK (idle_hours >= 0),
+    source TEXT NOT NULL,
+    recorded_at TIMESTAMPTZ NOT NULL,
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE TABLE fuel_economy_days (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    company_id UUID NOT NULL REFERENCES companies(id),
+    truck_id UUID NOT NULL REFERENCES trucks(id),
+    -- Unset for miles run while the truck had no driver on a load.
+    driver_id UUID REFERENCES drivers(id),
+    usage_date DATE NOT NULL,
+    miles DOUBLE PRECISION NOT NULL DEFAULT 0,
+    fuel_gallons DOUBLE PRECISION NOT NULL DEFAULT 0,
+    engine_hours DOUBLE PRECISION NOT NULL DEFAULT 0,
+    idle_hours DOUBLE PRECISION NOT NULL DEFAULT 0,
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE UNIQUE INDEX idx_fuel_economy_days_driver ON fuel_economy_days(truck_id, usage_date, driver_id) WHERE driver_id IS NOT NULL;
+CREATE UNIQUE INDEX idx_fuel_economy_days_unassigned ON fuel_economy_days(truck_id, usage_date) WHERE driver_id IS NULL;
+CREATE INDEX idx_fuel_economy_days_driver_date ON fuel_economy_days(driver_id, usage_date) WHERE driver_id IS NOT NULL;
+CREATE INDEX idx_fuel_economy_days_company ON fuel_economy_days(company_id, usage_date);
+
+-- A truck whose last week's MPG fell well short of the month before it,
+-- most often an injector, turbo or DPF problem. Open until someone
+-- resolves it.
+CREATE TABLE fuel_economy_flags (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    company_id UUID NOT NULL REFERENCES companies(id),
+    truck_id UUID NOT NULL REFERENCES trucks(id),
+    baseline_mpg DOUBLE PRECISION NOT NULL,
+    recent_mpg DOUBLE PRECISION NOT NULL,
+    drop_percentage DOUBLE PRECISION NOT NULL,
+    recent_miles DOUBLE PRECISION NOT NULL,
+    flagged_on DATE NOT NULL,
+    resolved_by UUID REFERENCES users(id),
+    resolution_note TEXT,
+    resolved_at TIMESTAMPTZ,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE UNIQUE INDEX idx_fuel_economy_flags_open ON fuel_economy_flags(truck_id) WHERE resolved_at IS NULL;
+CREATE INDEX idx_fuel_economy_flags_company ON fuel_economy_flags(company_id, created_at DESC);
@@ -0,0 +1,97 @@
+-- Collections on top of receivables. Dunning schedules mail customers
+-- about invoices coming due and past due; collectors keep notes and
+-- promises to pay against invoices; and a customer too far past due is
+-- put on a shipment hold that stops new freight being booked for it.
+
+CREATE TABLE dunning_schedules (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    company_id UUID NOT NULL REFERENCES companies(id),
+    name TEXT NOT NULL,
+    -- Used for customers without a schedule of their own.
+    is_default BOOLEAN NOT NULL DEFAULT FALSE,
+    active BOOLEAN NOT NULL DEFAULT TRUE,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    UNIQUE (company_id, name)
+);
+
+CREATE UNIQUE INDEX idx_dunning_schedules_default ON dunning_schedules(company_id) WHERE is_default;
+
+-- Each step mails the customer once per invoice, the given number of days
+-- after its due date; a negative number is a reminder before it.
+CREATE TABLE dunning_steps (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    schedule_id UUID NOT NULL REFERENCES dunning_schedules(id) ON DELETE CASCADE,
+    days_from_due INTEGER NOT NULL CHECK (days_from_due BETWEEN -30 AND 365),
+    tone TEXT NOT NULL CHECK (tone IN ('reminder', 'past_due', 'final_notice')),
+    message TEXT,
+    UNIQUE (schedule_id, days_from_due)
+);
+
+CREATE TABLE dunning_notices (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    company_id UUID NOT NULL REFERENCES companies(id),
+    invoice_id UUID NOT NULL REFERENCES invoices(id),
+    customer_id UUID NOT NULL REFERENCES customers(id),
+    days_from_due INTEGER NOT NULL,
+    tone TEXT NOT NULL,
+    recipient TEXT NOT NULL,
+    status TEXT NOT NULL CHECK (status IN ('sent', 'failed')),
+    error TEXT,
+    sent_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_dunning_notices_invoice ON dunning_notices(invoice_id, days_from_due);
+CREATE INDEX idx_dunning_notices_customer ON dunning_notices(customer_id, sent_at DESC);
+
+CREATE TABLE collection_notes (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    company_id UUID NOT NULL REFERENCES companies(id),
+    invoice_id UUID NOT NULL REFERENCES invoices(id),
+    customer_id UUID NOT NULL REFERENCES customers(id),
+    contact_method TEXT CHECK (contact_method IN ('phone', 'email', 'portal', 'in_person', 'other')),
+    note TEXT NOT NULL,
+    created_by UUID NOT NULL REFERENCES users(id),
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_collection_notes_invoice ON collection_notes(invoice_id, created_at DESC);
+CREATE INDEX idx_collection_notes_customer ON collection_notes(customer_id, created_at DESC);
+
+-- A promise is kept once the invoice's balance is down by the promised
+-- amount from where it stood when the promise was made, and broken when
+-- the promised date passes first.
+CREATE TABLE payment_promises (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    company_id UUID NOT NULL REFERENCES companies(id),
+    invoice_id UUID NOT NULL REFERENCES invoices(id),
+    customer_id UUID NOT NULL REFERENCES customers(id),
+    promised_amount NUMERIC(12, 2) NOT NULL CHECK (promised_amount > 0),
+    promised_date DATE NOT NULL,
+    balance_at_promise NUMERIC(12, 2) NOT NULL,
+    status TEXT NOT NULL DEFAULT 'pending' CHECK (status IN ('pending', 'kept', 'broken', 'cancelled')),
+    notes TEXT,
+    created_by UUID NOT NULL REFERENCES users(id),
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    resolved_at TIMESTAMPTZ
+);
+
+CREATE UNIQUE INDEX idx_payment_promises_pending ON payment_promises(invoice_id) WHERE status = 'pending';
+CREATE INDEX idx_payment_promises_customer ON payment_promises(customer_id, created_at DESC);
+
+ALTER TABLE customers
+    ADD COLUMN dunning_schedule_id UUID REFERENCES dunning_schedules(id),
+    ADD COLUMN collector_id UUID REFERENCES users(id),
+    ADD COLUMN shipment_hold BOOLEAN NOT NULL DEFAULT FALSE,
+    ADD COLUMN shipment_hold_reason TEXT,
+    -- Unset on holds the collections job placed, which it also lifts.
+    ADD COLUMN shipment_hold_by UUID REFERENCES users(id),
+    ADD COLUMN shipment_hold_at TIMESTAMPTZ,
+    -- No automatic hold is placed before this date.
+    ADD COLUMN shipment_hold_waived_until DATE;
+
+CREATE INDEX idx_customers_collector ON customers(collector_id) WHERE collector_id IS NOT NULL;
+
+-- How many days past due an invoice can get before its customer is put
+-- on hold; no automatic holds when unset.
+ALTER TABLE companies ADD COLUMN collections_hold_days INTEGER CHECK (collections_hold_days > 0);
@@ -0,0 +1,4 @@
+-- A user's own preferences, keyed like the company settings they
+-- override: units and locale, so a driver in Quebec can read kilometers
+-- and liters while dispatch in Texas keeps miles and gallons.
+ALTER TABLE users ADD COLUMN settings JSONB NOT NULL DEFAULT '{}';